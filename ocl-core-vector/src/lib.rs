@@ -17,9 +17,14 @@
 //! is definitely something to watch out for).
 //!
 //! Vector type fields can be accessed using index operations i.e. [0],
-//! [1], [2] ... etc. Plans for other ways of accessing fields (such as
-//! `.x()`, `.y()`, `.s0()`, `.s15()`, etc.) may be considered. Create an
-//! issue if you have an opinion on the matter.
+//! [1], [2] ... etc. or with the `.s0()`, `.s1()` ... `.s15()` accessors.
+//! Plans for other ways of accessing fields (such as `.x()`, `.y()`, etc.)
+//! may be considered. Create an issue if you have an opinion on the matter.
+//!
+//! Each vector type has the same size as its OpenCL counterpart. Note that
+//! three-component vectors are padded to the size of four components
+//! (`size_of::<Float3>() == 16`), as they are within kernels. Vectors
+//! converted to and from arrays use the unpadded length (`[f32; 3]`).
 //!
 //! [NOTE]: This module may be renamed.
 
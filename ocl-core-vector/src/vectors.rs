@@ -440,6 +440,14 @@ macro_rules! impl_common {
             pub fn one() -> Self {
                 $name::from([One::one(); $cardinality])
             }
+
+            $(
+                /// Returns the value of this component.
+                #[inline]
+                pub fn $field(&self) -> $ty {
+                    self.0[$idx]
+                }
+            )+
         }
 
         impl Deref for $name {
@@ -573,6 +581,7 @@ macro_rules! impl_cl_vec {
 // exists, if ever.
 macro_rules! decl_impl_cl_vec {
     ($name:ident, 1, $ty:ty, $ty_fam:ident, $( $field:ident ),+: $( $tr:ty ),+: $( $idx:expr ),+) => {
+        #[repr(C)]
         #[derive(Debug, Clone, Copy, Default, PartialOrd)]
         pub struct $name([$ty; 1]);
 
@@ -621,6 +630,7 @@ macro_rules! decl_impl_cl_vec {
         impl_cl_vec!($name, 1, $ty, $ty_fam, $( $field ),+: $( $tr ),+: $( $idx ),+ );
     };
     ($name:ident, 3, $ty:ty, $ty_fam:ident, $( $field:ident ),+: $( $tr:ty ),+: $( $idx:expr ),+) => {
+        #[repr(C)]
         #[derive(Debug, Clone, Copy, Default, PartialOrd)]
         pub struct $name([$ty; 4]);
 
@@ -655,6 +665,7 @@ macro_rules! decl_impl_cl_vec {
         impl_cl_vec!($name, 3, $ty, $ty_fam, $( $field ),+: $( $tr ),+: $( $idx ),+ );
     };
     ($name:ident, $cardinality:expr, $ty:ty, $ty_fam:ident, $( $field:ident ),+: $( $tr:ty ),+: $( $idx:expr ),+) => {
+        #[repr(C)]
         #[derive(Debug, Clone, Copy, Default, PartialOrd)]
         pub struct $name([$ty; $cardinality]);
 
@@ -1276,7 +1287,7 @@ cl_vec!(Double16, 16, f64, f);
 
 // Copied from `https://doc.rust-lang.org/src/core/num/wrapping.rs.html`.
 mod shift_max {
-    #![allow(non_upper_case_globals, dead_code)]
+    #![allow(non_upper_case_globals, dead_code, unused_imports)]
 
     #[cfg(target_pointer_width = "16")]
     mod platform {
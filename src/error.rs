@@ -80,6 +80,7 @@ pub enum ErrorKind {
     IntoStringError(::std::ffi::IntoStringError),
     EmptyInfoResult(EmptyInfoResult),
     VersionLow { detected: OpenclVersion, required: OpenclVersion },
+    TimedOut(::std::time::Duration),
     Other(Box<StdError>),
 }
 
@@ -117,6 +118,11 @@ impl self::Error {
         Error { kind: ErrorKind::VersionLow { detected, required }, cause: None }
     }
 
+    /// Returns an `Error` with the `TimedOut` kind variant.
+    pub fn timed_out(timeout: ::std::time::Duration) -> Error {
+        Error { kind: ErrorKind::TimedOut(timeout), cause: None }
+    }
+
     /// Returns a new `ocl_core::Result::Err` containing an
     /// `ocl_core::ErrorKind::String` variant with the given description.
     ///
@@ -178,6 +184,22 @@ impl self::Error {
         Error { kind: err.kind, cause: Some(Box::new(self)) }
     }
 
+    /// Creates a new error, naming the function (and optional details about
+    /// the call) during which this error occurred, with this error as its
+    /// cause.
+    ///
+    /// Useful for non-`Status` errors, which otherwise carry no record of
+    /// the OpenCL function which triggered them.
+    pub fn with_fn_info<S: Into<String>>(self, fn_name: &'static str, fn_info: S) -> Self {
+        let fn_info = fn_info.into();
+        let desc = if fn_info.is_empty() {
+            format!("Error executing function: {}", fn_name)
+        } else {
+            format!("Error executing function: {}(\"{}\")", fn_name, fn_info)
+        };
+        self.chain(desc)
+    }
+
     /// Returns the error status code for `Status` variants.
     pub fn status(&self) -> Option<Status> {
         match self.kind {
@@ -186,6 +208,20 @@ impl self::Error {
         }
     }
 
+    /// Returns true if this error, or any error in its chain of causes, may
+    /// succeed if the operation is retried.
+    ///
+    /// This is the case for `TimedOut` errors and for `Status` errors whose
+    /// status is transient (see `Status::is_transient`).
+    pub fn is_transient(&self) -> bool {
+        let transient = match self.kind {
+            ErrorKind::Status { ref status, .. } => status.is_transient(),
+            ErrorKind::TimedOut(_) => true,
+            _ => false,
+        };
+        transient || self.cause().map(|c| c.is_transient()).unwrap_or(false)
+    }
+
     /// Returns the error variant and contents.
     pub fn kind(&self) -> &ErrorKind {
         &self.kind
@@ -217,6 +253,8 @@ impl self::Error {
                 ErrorKind::UnspecifiedDimensions => write!(f, "Cannot convert to a valid set of \
                     dimensions. Please specify some dimensions."),
                 ErrorKind::EmptyInfoResult(ref err) => write!(f, "{}", err.description()),
+                ErrorKind::TimedOut(timeout) => write!(f, "Operation timed out after {}.{:03}s.",
+                    timeout.as_secs(), timeout.subsec_millis()),
                 ErrorKind::Other(ref err) => write!(f, "{}", err.description()),
                 // _ => f.write_str(self.description()),
             }
@@ -262,6 +300,7 @@ impl StdError for self::Error {
                 Please specify some dimensions.",
             ErrorKind::EmptyInfoResult(ref err) => err.description(),
             ErrorKind::VersionLow { .. } => "OpenCL version too low to use this feature.",
+            ErrorKind::TimedOut(_) => "Operation timed out.",
             ErrorKind::Other(ref err) => err.description(),
             // _ => panic!("OclErrorKind::description()"),
         }
@@ -357,4 +396,14 @@ impl<T> ChainErr<T, Error> for self::Result<T> {
             self::Error { kind: err.kind, cause: Some(Box::new(e)) }
         })
     }
+}
+
+impl<T> ChainErr<T, Error> for Option<T> {
+    /// If the `Option` is `None`, evaluates the closure and returns the
+    /// resulting error. There is no prior error to store as the cause.
+    fn chain_err<F, E>(self, callback: F) -> self::Result<T>
+            where F: FnOnce() -> E, E: Into<self::Error>
+    {
+        self.ok_or_else(move || callback().into())
+    }
 }
\ No newline at end of file
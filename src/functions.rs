@@ -5,16 +5,22 @@
 //!
 
 use std::ptr;
+use std::cmp;
 use std::mem;
-use std::ffi::CString;
+use std::slice;
+use std::ffi::{CString, CStr};
 use std::iter;
 // #[cfg(any(feature="kernel_debug_sleep", target_os="windows"))] use std::thread;
 // #[cfg(any(feature="kernel_debug_sleep", target_os="windows"))] use std::time::Duration;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use std::sync::{Arc, Mutex, Condvar, Once, ONCE_INIT};
+use std::sync::atomic::{AtomicUsize, ATOMIC_USIZE_INIT, Ordering};
+use std::collections::{HashMap, HashSet};
 use std::env;
+use std::panic;
 use std::fmt::Debug;
-use libc::{size_t, c_void};
+use libc::{size_t, c_void, c_char};
 use num::FromPrimitive;
 
 use ffi::{cl_GLuint, cl_GLint, cl_GLenum, cl_gl_context_info,};
@@ -41,10 +47,13 @@ use ::{OclPrm, PlatformId, DeviceId, Context, ContextProperties, ContextInfo, Gl
     ProgramBuildInfo, ProgramBuildInfoResult, KernelInfo, KernelInfoResult, KernelArgInfo,
     KernelArgInfoResult, KernelWorkGroupInfo, KernelWorkGroupInfoResult, ClEventPtrRef,
     ClWaitListPtr, EventInfo, EventInfoResult, ProfilingInfo, ProfilingInfoResult,
-    CreateContextCallbackFn, UserDataPtr, ClPlatformIdPtr, ClDeviceIdPtr, ClContextPtr,
+    CreateContextCallbackFn, ContextCallback, UserDataPtr, ClPlatformIdPtr, ClDeviceIdPtr, ClContextPtr,
     EventCallbackFn, BuildProgramCallbackFn, MemMigrationFlags, MapFlags, BufferRegion,
     BufferCreateType, OpenclVersion, ClVersions, Status, CommandQueueProperties, MemMap, AsMem,
-    MemCmdRw, MemCmdAll, Event, ImageFormatParseResult};
+    MemCmdRw, MemCmdAll, Event, ImageFormatParseResult, Profile, MappedMem, ProfilingSummary,
+    ProfilingSink,
+    DeviceExecCapabilities, ProgramBuildStatus, DevicePartition, DeviceSummary, DeviceFpConfig,
+    DeviceSvmCapabilities, ClGlObjectType, GlTextureInfoResult, EventStatus};
 
 
 // [TODO]: Do proper auto-detection of available OpenGL context type.
@@ -103,6 +112,165 @@ pub extern "C" fn _complete_user_event(src_event_ptr: cl_event, event_status: i3
     }
 }
 
+/// Reconstructs the boxed closure passed as `user_data` by
+/// `::set_event_callback_closure`, calls it, then drops it.
+///
+/// The closure is consumed on the first (and only) invocation, OpenCL never
+/// calls a registered callback more than once.
+extern "C" fn _event_callback_trampoline<F>(src_event_ptr: cl_event, event_status: i32,
+        user_data: *mut c_void)
+        where F: FnMut(Event, i32) + Send + 'static
+{
+    if user_data.is_null() { return; }
+    let mut callback: Box<F> = unsafe { Box::from_raw(user_data as *mut F) };
+
+    // Unwinding across the FFI boundary is undefined; abort the callback
+    // rather than the process. If the event can not be retained the closure
+    // is dropped without being called:
+    let _ = panic::catch_unwind(panic::AssertUnwindSafe(move || {
+        if let Ok(event) = unsafe { Event::from_raw_copied_ptr(src_event_ptr) } {
+            callback(event, event_status);
+        }
+    }));
+}
+
+/// Reconstructs and calls the boxed closure passed as `user_data` by
+/// `::set_mem_object_destructor_callback`.
+extern "C" fn _mem_destructor_trampoline<F>(_: cl_mem, user_data: *mut c_void)
+        where F: FnOnce() + Send + 'static
+{
+    if user_data.is_null() { return; }
+    let callback: Box<F> = unsafe { Box::from_raw(user_data as *mut F) };
+
+    // Unwinding across the FFI boundary is undefined:
+    let _ = panic::catch_unwind(panic::AssertUnwindSafe(move || (*callback)()));
+}
+
+/// Unpacks the argument block built by `::enqueue_native_kernel` and calls
+/// the boxed closure it contains.
+///
+/// The block is laid out as `[closure_ptr, mem_count, mem_ptr_0, ...]`, with
+/// each `mem_ptr` having been replaced by the implementation with a pointer
+/// to the corresponding memory object's global memory.
+extern "C" fn _native_kernel_trampoline<F>(args: *mut c_void)
+        where F: FnOnce(&[*mut c_void]) + Send + 'static
+{
+    if args.is_null() { return; }
+    let args = args as *mut *mut c_void;
+
+    let (callback, mem_ptrs) = unsafe {
+        let callback: Box<F> = Box::from_raw(*args as *mut F);
+        let mem_count = *args.offset(1) as usize;
+        (callback, slice::from_raw_parts(args.offset(2), mem_count))
+    };
+
+    // A panicking native kernel must not unwind into the implementation:
+    let _ = panic::catch_unwind(panic::AssertUnwindSafe(move || (*callback)(mem_ptrs)));
+}
+
+/// Frees the shared virtual memory regions passed to `::enqueue_svm_free`
+/// then calls the boxed closure passed as `user_data`.
+///
+/// `user_data` is a boxed `(Context, F)` pair: the context is needed for
+/// `clSVMFree` and is released once the closure has been called.
+#[cfg(feature = "opencl_version_2_0")]
+extern "C" fn _svm_free_trampoline<F>(_: cl_command_queue, num_svm_pointers: cl_uint,
+        svm_pointers: *const *const c_void, user_data: *mut c_void)
+        where F: FnOnce() + Send + 'static
+{
+    if user_data.is_null() { return; }
+    let data: Box<(Context, F)> = unsafe { Box::from_raw(user_data as *mut _) };
+    let (context, callback) = *data;
+
+    if !svm_pointers.is_null() {
+        let svm_ptrs = unsafe { slice::from_raw_parts(svm_pointers, num_svm_pointers as usize) };
+        for &svm_ptr in svm_ptrs {
+            unsafe { ffi::clSVMFree(context.as_ptr(), svm_ptr as *mut c_void); }
+        }
+    }
+
+    let _ = panic::catch_unwind(panic::AssertUnwindSafe(move || callback()));
+}
+
+/// Marks the build started by `::build_program_with_timeout` as finished and
+/// wakes the waiting thread.
+///
+/// `user_data` is a boxed `Arc` of the shared `(finished, condvar)` pair and
+/// is dropped here (whether or not the waiting thread has since timed out).
+extern "C" fn _build_program_notify(_: *mut c_void, user_data: *mut c_void) {
+    if user_data.is_null() { return; }
+    let pair: Box<Arc<(Mutex<bool>, Condvar)>> = unsafe { Box::from_raw(user_data as *mut _) };
+    let &(ref finished, ref cvar) = &**pair;
+
+    if let Ok(mut finished) = finished.lock() {
+        *finished = true;
+    }
+    cvar.notify_all();
+}
+
+/// The program and closure passed to `::build_program_async`, shared between
+/// it and `_build_program_async_trampoline`. Whichever side takes the pair
+/// out first is responsible for it.
+type BuildAsyncState<F> = Mutex<Option<(Program, F)>>;
+
+/// Reclaims the reference to the shared state passed as `user_data` by
+/// `::build_program_async` and, unless it has already been taken, calls the
+/// closure with the program.
+extern "C" fn _build_program_async_trampoline<F>(_: *mut c_void, user_data: *mut c_void)
+        where F: FnOnce(Program) + Send + 'static
+{
+    if user_data.is_null() { return; }
+    let state: Arc<BuildAsyncState<F>> = unsafe { Arc::from_raw(user_data as *const _) };
+    let data = match state.lock() {
+        Ok(mut data) => data.take(),
+        Err(_) => None,
+    };
+
+    if let Some((program, callback)) = data {
+        let _ = panic::catch_unwind(panic::AssertUnwindSafe(move || callback(program)));
+    }
+}
+
+/// Calls the callback passed as `user_data` by `::create_context_with_callback`
+/// with the error information and implementation-specific private data
+/// reported by the implementation.
+///
+/// The callback is not consumed: it may be called any number of times, from
+/// any thread, until the context is destroyed.
+extern "C" fn _context_callback_trampoline(errinfo: *const c_char, private_info: *const c_void,
+        cb: size_t, user_data: *mut c_void)
+{
+    if user_data.is_null() || errinfo.is_null() { return; }
+    let callback = unsafe { &*(user_data as *const Mutex<ContextCallback>) };
+    let errinfo = unsafe { CStr::from_ptr(errinfo) }.to_string_lossy();
+    let private_info = if private_info.is_null() || cb == 0 {
+        &[][..]
+    } else {
+        unsafe { slice::from_raw_parts(private_info as *const u8, cb) }
+    };
+
+    let mut callback = match callback.lock() {
+        Ok(cb) => cb,
+        Err(_) => return,
+    };
+
+    // Unwinding across the FFI boundary is undefined:
+    let _ = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        (**callback)(&errinfo, private_info)
+    }));
+}
+
+/// Drops the callback passed as `user_data` by `::create_context_with_callback`
+/// once the implementation reports that the context has been destroyed (after
+/// which the callback can no longer be called).
+#[cfg(feature = "opencl_version_3_0")]
+extern "C" fn _context_destructor_trampoline(_: cl_context, user_data: *mut c_void) {
+    if user_data.is_null() { return; }
+    let _ = panic::catch_unwind(|| unsafe {
+        drop(Box::from_raw(user_data as *mut Mutex<ContextCallback>));
+    });
+}
+
 //============================================================================
 //============================================================================
 //=========================== SUPPORT FUNCTIONS ==============================
@@ -188,6 +356,27 @@ pub fn program_build_err<D: ClDeviceIdPtr>(program: &Program, device_ids: &[D])
     Ok(())
 }
 
+// Returns an error containing the build log if the most recent build of
+// `program` did not succeed for every device in `device_ids`.
+fn verify_program_built<D: ClDeviceIdPtr>(program: &Program, device_ids: &[D])
+        -> OclResult<()>
+{
+    for &device_id in device_ids {
+        match get_program_build_info(program, device_id, ProgramBuildInfo::BuildStatus) {
+            ProgramBuildInfoResult::BuildStatus(ProgramBuildStatus::Success) => (),
+            ProgramBuildInfoResult::BuildStatus(status) => {
+                program_build_err(program, device_ids)?;
+                return OclError::err_string(format!("Program build did not succeed \
+                    (device: {:?}, status: {:?}).", device_id, status));
+            },
+            ProgramBuildInfoResult::Error(err) => return Err(*err),
+            _ => unreachable!(),
+        }
+    }
+
+    Ok(())
+}
+
 /// Verifies that OpenCL versions are above a specified threshold.
 pub fn verify_versions(versions: &[OpenclVersion], required_version: [u16; 2]) -> OclResult<()> {
     let reqd_ver = OpenclVersion::from(required_version);
@@ -203,6 +392,34 @@ pub fn verify_versions(versions: &[OpenclVersion], required_version: [u16; 2]) -
     Ok(())
 }
 
+/// Returns an error with the `VersionLow` kind if any of the OpenCL versions
+/// reported by `device` are lower than `required`.
+///
+/// Accepts a `DeviceId` or anything else which reports device versions
+/// (`Context`, `CommandQueue`, etc.).
+pub fn require_device_version<V: ClVersions>(device: V, required: OpenclVersion)
+        -> OclResult<()>
+{
+    for detected in device.device_versions()? {
+        if detected < required {
+            return Err(OclError::version_low(detected, required));
+        }
+    }
+    Ok(())
+}
+
+/// Returns an error with the `VersionLow` kind if the OpenCL version
+/// reported by `platform` is lower than `required`.
+pub fn require_platform_version<V: ClVersions>(platform: V, required: OpenclVersion)
+        -> OclResult<()>
+{
+    let detected = platform.platform_version()?;
+    if detected < required {
+        return Err(OclError::version_low(detected, required));
+    }
+    Ok(())
+}
+
 // Verifies that a platform version (`provided_version`) is above a threshold
 // (`required_version`).
 fn verify_platform_version<V: ClVersions>(provided_version: Option<&OpenclVersion>,
@@ -238,6 +455,173 @@ fn verify_device_versions<V: ClVersions>(provided_versions: Option<&[OpenclVersi
     }
 }
 
+// Verifies that a memory object is a buffer and not an image or pipe.
+fn verify_mem_is_buffer(mem: &Mem, fn_name: &'static str) -> OclResult<()> {
+    match mem.mem_type() {
+        Ok(MemObjectType::Buffer) => Ok(()),
+        Ok(mem_type) => OclError::err_string(format!("{}: Invalid memory object type: '{:?}'. \
+            Only buffers may be used with this function.", fn_name, mem_type)),
+        Err(err) => Err(err),
+    }
+}
+
+// Verifies that the host may read from (`host_read`) or write to (`!host_read`)
+// a memory object according to its host access flags.
+fn verify_mem_host_access(mem: &Mem, host_read: bool, fn_name: &'static str) -> OclResult<()> {
+    let flags = mem.flags().chain_err(|| fn_name)?;
+
+    let forbidden = if host_read {
+        flags & (::MEM_HOST_WRITE_ONLY | ::MEM_HOST_NO_ACCESS)
+    } else {
+        flags & (::MEM_HOST_READ_ONLY | ::MEM_HOST_NO_ACCESS)
+    };
+
+    if forbidden.is_empty() {
+        Ok(())
+    } else {
+        OclError::err_string(format!("{}: The host may not {} this memory object \
+            (created with: {:?}).", fn_name, if host_read { "read from" } else { "write to" },
+            forbidden))
+    }
+}
+
+//============================================================================
+//========================= MEM GENERATION TRACKING ==========================
+//============================================================================
+
+// Memory objects and contexts tracked by `CachedReader`s:
+// `cl_mem` / `cl_context` -> (tracker count, generation).
+//
+// A context's generation is bumped by any command which may write to memory
+// objects which are not individually tracked (kernels, native kernels, GL
+// acquisitions).
+//
+// Each `CachedReader` holds a reference to both the memory object and the
+// context it tracks, neither address can therefore be reused by another
+// object while an entry exists.
+struct MemGenerations {
+    mems: HashMap<usize, (usize, usize)>,
+    contexts: HashMap<usize, (usize, usize)>,
+}
+
+static MEM_GENERATIONS_INIT: Once = ONCE_INIT;
+static MEM_GENERATIONS: AtomicUsize = ATOMIC_USIZE_INIT;
+
+// The number of live trackers (`CachedReader`s). While zero, the hooks in
+// the enqueue functions return without locking or querying anything.
+static MEM_GENERATION_TRACKERS: AtomicUsize = ATOMIC_USIZE_INIT;
+
+#[inline]
+fn mem_generations_tracked() -> bool {
+    MEM_GENERATION_TRACKERS.load(Ordering::SeqCst) != 0
+}
+
+fn mem_generations() -> &'static Mutex<MemGenerations> {
+    MEM_GENERATIONS_INIT.call_once(|| {
+        let gens = Box::new(Mutex::new(MemGenerations {
+            mems: HashMap::new(),
+            contexts: HashMap::new(),
+        }));
+        MEM_GENERATIONS.store(Box::into_raw(gens) as usize, Ordering::SeqCst);
+    });
+    // Set exactly once above and never freed:
+    unsafe { &*(MEM_GENERATIONS.load(Ordering::SeqCst) as *const Mutex<MemGenerations>) }
+}
+
+fn track_generation(gens: &mut HashMap<usize, (usize, usize)>, key: usize) {
+    gens.entry(key).or_insert((0, 0)).0 += 1;
+}
+
+fn untrack_generation(gens: &mut HashMap<usize, (usize, usize)>, key: usize) {
+    let remove = match gens.get_mut(&key) {
+        Some(entry) => { entry.0 -= 1; entry.0 == 0 },
+        None => false,
+    };
+    if remove { gens.remove(&key); }
+}
+
+/// Begins tracking writes to `mem`, which must belong to `context`. Each call
+/// must be paired with a call to `untrack_mem_generation`.
+pub(crate) fn track_mem_generation(context: &Context, mem: &Mem) {
+    let mut gens = mem_generations().lock().unwrap();
+    track_generation(&mut gens.mems, mem.as_ptr() as usize);
+    track_generation(&mut gens.contexts, context.as_ptr() as usize);
+    MEM_GENERATION_TRACKERS.fetch_add(1, Ordering::SeqCst);
+}
+
+/// Stops tracking writes to `mem` (and `context`) once no trackers remain.
+pub(crate) fn untrack_mem_generation(context: &Context, mem: &Mem) {
+    let mut gens = mem_generations().lock().unwrap();
+    untrack_generation(&mut gens.mems, mem.as_ptr() as usize);
+    untrack_generation(&mut gens.contexts, context.as_ptr() as usize);
+    MEM_GENERATION_TRACKERS.fetch_sub(1, Ordering::SeqCst);
+}
+
+/// Returns the current (per-object, per-context) generation of `mem`.
+pub(crate) fn mem_generation(context: &Context, mem: &Mem) -> (usize, usize) {
+    let gens = mem_generations().lock().unwrap();
+    let local = gens.mems.get(&(mem.as_ptr() as usize)).map(|entry| entry.1).unwrap_or(0);
+    let ctx = gens.contexts.get(&(context.as_ptr() as usize)).map(|entry| entry.1).unwrap_or(0);
+    (local, ctx)
+}
+
+// Marks `mem` as (potentially) written to if it is being tracked.
+fn bump_mem_generation(mem: &Mem) {
+    if !mem_generations_tracked() { return; }
+
+    let mut gens = mem_generations().lock().unwrap();
+    if let Some(entry) = gens.mems.get_mut(&(mem.as_ptr() as usize)) {
+        entry.1 = entry.1.wrapping_add(1);
+    }
+}
+
+// Marks all memory objects in the context of `command_queue` as
+// (potentially) written to. The context is only looked up while some
+// context is being tracked.
+fn bump_context_mem_generation(command_queue: &CommandQueue) {
+    if !mem_generations_tracked() { return; }
+
+    if let Ok(context_ptr) = command_queue.context_ptr() {
+        let mut gens = mem_generations().lock().unwrap();
+        if let Some(entry) = gens.contexts.get_mut(&(context_ptr as usize)) {
+            entry.1 = entry.1.wrapping_add(1);
+        }
+    }
+}
+
+//============================================================================
+//============================= PROFILING SINKS ==============================
+//============================================================================
+
+/// Registers `sink` to receive the execution duration of each kernel
+/// enqueued on `command_queue` with `enqueue_kernel_named`, replacing any
+/// previously registered sink. Passing `None` unregisters it.
+///
+/// The sink is stored in `command_queue` itself and is copied to any clone
+/// made afterwards. Other handles to the same queue (such as those returned
+/// by `get_event_info`) do not carry it.
+///
+/// `command_queue` must have been created with `QUEUE_PROFILING_ENABLE`.
+pub fn set_profiling_sink(command_queue: &mut CommandQueue, sink: Option<Arc<ProfilingSink>>)
+        -> OclResult<()>
+{
+    if sink.is_some() {
+        match get_command_queue_info(command_queue, CommandQueueInfo::Properties) {
+            CommandQueueInfoResult::Properties(props) => {
+                if !props.contains(::QUEUE_PROFILING_ENABLE) {
+                    return OclError::err_string("::set_profiling_sink: The command queue \
+                        was not created with 'QUEUE_PROFILING_ENABLE'.");
+                }
+            },
+            CommandQueueInfoResult::Error(err) => return Err(*err),
+            _ => unreachable!(),
+        }
+    }
+
+    command_queue.set_profiling_sink(sink);
+    Ok(())
+}
+
 //============================================================================
 //============================================================================
 //======================= OPENCL FUNCTION WRAPPERS ===========================
@@ -312,9 +696,31 @@ pub fn get_platform_ids() -> OclResult<Vec<PlatformId>> {
 }
 
 /// Returns platform information of the requested type.
+///
+/// `PlatformInfo::HostTimerResolution` is [Version Controlled: OpenCL 2.1+]
+/// and `PlatformInfo::ExtensionsWithVersion` is [Version Controlled: OpenCL
+/// 3.0+]. See module docs for more info.
 pub fn get_platform_info<P: ClPlatformIdPtr>(platform: P, request: PlatformInfo,
         ) -> PlatformInfoResult
 {
+    let required_version = match request {
+        PlatformInfo::HostTimerResolution => Some([2, 1]),
+        PlatformInfo::ExtensionsWithVersion => Some([3, 0]),
+        _ => None,
+    };
+
+    if let Some(required_version) = required_version {
+        let platform_version = match get_platform_info(platform, PlatformInfo::Version)
+                .as_opencl_version() {
+            Ok(pv) => pv,
+            Err(err) => return PlatformInfoResult::Error(Box::new(err)),
+        };
+
+        if let Err(err) = verify_versions(&[platform_version], required_version) {
+            return PlatformInfoResult::Error(Box::new(err));
+        }
+    }
+
     let mut result_size = 0 as size_t;
 
     let errcode = unsafe {
@@ -357,6 +763,10 @@ pub fn get_platform_info<P: ClPlatformIdPtr>(platform: P, request: PlatformInfo,
 //============================================================================
 
 /// Returns a list of available devices for a particular platform.
+///
+/// `device_types` defaults to the value returned by `::default_device_type`
+/// (`DEVICE_TYPE_ALL` unless overridden by environment variable). An empty
+/// list is returned if the platform has no devices of the requested types.
 pub fn get_device_ids<P: ClPlatformIdPtr>(
             platform: P,
             device_types: Option<DeviceType>,
@@ -388,10 +798,14 @@ pub fn get_device_ids<P: ClPlatformIdPtr>(
         device_ids.as_mut_ptr() as *mut cl_device_id,
         &mut devices_available,
     ) };
+
+    if errcode == Status::CL_DEVICE_NOT_FOUND as i32 {
+        return Ok(Vec::new());
+    }
     try!(eval_errcode(errcode, (), "clGetDeviceIDs", ""));
 
-    // Trim vec len:
-    unsafe { device_ids.set_len(devices_available as usize); }
+    // Trim vec len (`devices_available` may exceed `devices_max`):
+    unsafe { device_ids.set_len(cmp::min(devices_available, devices_max) as usize); }
     device_ids.shrink_to_fit();
 
     Ok(device_ids)
@@ -418,7 +832,33 @@ pub fn get_device_info<D: ClDeviceIdPtr>(device: D, request: DeviceInfo)
     // function and is a bug. Don't hold your breath for a fix.
     if errcode < 0 {
         if Status::from_i32(errcode).unwrap() == Status::CL_INVALID_VALUE {
-            return OclError::from("<unavailable (CL_INVALID_VALUE)>").into();
+            match request {
+                // Devices lacking double or half precision support may reject
+                // these queries outright rather than reporting an empty
+                // config. Any other cause is reported in full below:
+                DeviceInfo::DoubleFpConfig => {
+                    if let Ok(false) = device_has_extension(device, "cl_khr_fp64") {
+                        return DeviceInfoResult::DoubleFpConfig(DeviceFpConfig::empty());
+                    }
+                },
+                DeviceInfo::HalfFpConfig => {
+                    if let Ok(false) = device_has_fp16(device) {
+                        return DeviceInfoResult::HalfFpConfig(DeviceFpConfig::empty());
+                    }
+                },
+                // Pre-2.0 devices have no SVM support to report:
+                DeviceInfo::SvmCapabilities => {
+                    let device_id = unsafe { DeviceId::from_raw(device.as_ptr()) };
+                    match device_id.version() {
+                        Ok(ver) if ver < OpenclVersion::CL20 => {
+                            return DeviceInfoResult::SvmCapabilities(
+                                DeviceSvmCapabilities::empty());
+                        },
+                        _ => (),
+                    }
+                },
+                _ => return OclError::from("<unavailable (CL_INVALID_VALUE)>").into(),
+            }
         } else if Status::from_i32(errcode).unwrap() == Status::CL_INVALID_OPERATION {
             return OclError::from("<unavailable (CL_INVALID_OPERATION)>").into();
         }
@@ -459,18 +899,79 @@ pub fn get_device_info<D: ClDeviceIdPtr>(device: D, request: DeviceInfo)
     }
 }
 
-/// [UNIMPLEMENTED]
+/// Partitions `device` into sub-devices according to `partition` and returns
+/// them.
+///
+/// The number of sub-devices requested by an `Equally` or `ByCounts`
+/// partition is validated against `DeviceInfo::PartitionMaxSubDevices`
+/// before any partitioning is attempted.
+///
+/// Each returned sub-device holds a reference which must be released using
+/// `::release_device`.
+///
+/// [SDK Docs](https://www.khronos.org/registry/cl/sdk/1.2/docs/man/xhtml/clCreateSubDevices.html)
 ///
 /// [Version Controlled: OpenCL 1.2+] See module docs for more info.
-pub fn create_sub_devices(device_version: Option<&OpenclVersion>) -> OclResult<()> {
-    // clCreateSubDevices(in_device: cl_device_id,
-    //                    properties: *const cl_device_partition_property,
-    //                    num_devices: cl_uint,
-    //                    out_devices: *mut cl_device_id,
-    //                    num_devices_ret: *mut cl_uint) -> cl_int;
+pub fn create_sub_devices(device: &DeviceId, partition: &DevicePartition,
+            device_version: Option<&OpenclVersion>) -> OclResult<Vec<DeviceId>>
+{
+    verify_device_version(device_version, [1, 2], device)
+        .chain_err(|| "::create_sub_devices")?;
 
-    let _ = device_version;
-    unimplemented!();
+    let max_sub_devices = match get_device_info(device, DeviceInfo::PartitionMaxSubDevices) {
+        DeviceInfoResult::PartitionMaxSubDevices(max) => max,
+        DeviceInfoResult::Error(err) => return Err(*err),
+        _ => unreachable!(),
+    };
+
+    let requested = match *partition {
+        DevicePartition::Equally(0) => {
+            return OclError::err_string("::create_sub_devices: The number of compute units per \
+                sub-device must be greater than zero.");
+        },
+        DevicePartition::Equally(cu_count) => {
+            match get_device_info(device, DeviceInfo::MaxComputeUnits) {
+                DeviceInfoResult::MaxComputeUnits(cus) => Some(cus / cu_count),
+                DeviceInfoResult::Error(err) => return Err(*err),
+                _ => unreachable!(),
+            }
+        },
+        DevicePartition::ByCounts(ref counts) => Some(counts.len() as u32),
+        DevicePartition::ByAffinityDomain(_) => None,
+    };
+
+    if let Some(requested) = requested {
+        if requested > max_sub_devices {
+            return OclError::err_string(format!("::create_sub_devices: The requested number \
+                of sub-devices ({}) exceeds the maximum supported by the device ({}).",
+                requested, max_sub_devices));
+        }
+    }
+
+    let props = partition.to_raw();
+    let mut num_devices: cl_uint = 0;
+
+    let errcode = unsafe { ffi::clCreateSubDevices(
+        device.as_ptr(),
+        props.as_ptr(),
+        0,
+        ptr::null_mut(),
+        &mut num_devices,
+    ) };
+    eval_errcode(errcode, (), "clCreateSubDevices", "")?;
+
+    let mut sub_devices: Vec<DeviceId> = iter::repeat(unsafe { DeviceId::null() })
+        .take(num_devices as usize).collect();
+
+    let errcode = unsafe { ffi::clCreateSubDevices(
+        device.as_ptr(),
+        props.as_ptr(),
+        num_devices,
+        sub_devices.as_mut_ptr() as *mut cl_device_id,
+        ptr::null_mut(),
+    ) };
+
+    eval_errcode(errcode, sub_devices, "clCreateSubDevices", "")
 }
 
 /// Increments the reference count of a device.
@@ -493,6 +994,96 @@ pub unsafe fn release_device(device: &DeviceId, device_version: Option<&OpenclVe
     eval_errcode(ffi::clReleaseDevice(device.as_ptr()), (), "clReleaseDevice", "")
 }
 
+// `cl-sys` declares the timestamp out-parameters of these as `cl_ulong`
+// rather than `*mut cl_ulong`. Declare them locally with the correct
+// signatures.
+#[cfg(feature = "opencl_version_2_1")]
+extern "system" {
+    fn clGetDeviceAndHostTimer(device: cl_device_id, device_timestamp: *mut ffi::cl_ulong,
+        host_timestamp: *mut ffi::cl_ulong) -> cl_int;
+    fn clGetHostTimer(device: cl_device_id, host_timestamp: *mut ffi::cl_ulong) -> cl_int;
+}
+
+/// Returns a reasonably synchronized pair of timestamps from the device timer
+/// and the host timer as seen by `device`, in the layout
+/// `(device_timestamp, host_timestamp)` (both in nanoseconds).
+///
+/// Sampling this pair at two points in time allows device profiling
+/// timestamps to be mapped linearly onto host time.
+///
+/// [Version Controlled: OpenCL 2.1+] See module docs for more info.
+#[cfg(feature = "opencl_version_2_1")]
+pub fn get_device_and_host_timer(device: &DeviceId, device_version: Option<&OpenclVersion>)
+            -> OclResult<(u64, u64)> {
+    verify_device_version(device_version, [2, 1], device)
+        .chain_err(|| "::get_device_and_host_timer")?;
+
+    let mut device_timestamp: ffi::cl_ulong = 0;
+    let mut host_timestamp: ffi::cl_ulong = 0;
+
+    let errcode = unsafe { clGetDeviceAndHostTimer(
+        device.as_ptr(),
+        &mut device_timestamp,
+        &mut host_timestamp,
+    ) };
+
+    eval_errcode(errcode, (device_timestamp, host_timestamp), "clGetDeviceAndHostTimer", "")
+}
+
+/// Returns the current value of the host clock as seen by `device`, in
+/// nanoseconds.
+///
+/// [Version Controlled: OpenCL 2.1+] See module docs for more info.
+#[cfg(feature = "opencl_version_2_1")]
+pub fn get_host_timer(device: &DeviceId, device_version: Option<&OpenclVersion>)
+            -> OclResult<u64> {
+    verify_device_version(device_version, [2, 1], device)
+        .chain_err(|| "::get_host_timer")?;
+
+    let mut host_timestamp: ffi::cl_ulong = 0;
+
+    let errcode = unsafe { clGetHostTimer(
+        device.as_ptr(),
+        &mut host_timestamp,
+    ) };
+
+    eval_errcode(errcode, host_timestamp, "clGetHostTimer", "")
+}
+
+/// Replaces the default on-device command queue of `device` with `queue`,
+/// which is then used by kernels calling the `enqueue_kernel` built-in.
+///
+/// `queue` must be an on-device queue (created with `QUEUE_ON_DEVICE`, see
+/// `CommandQueueProperties::on_device`) for `device` within `context`.
+///
+/// [Version Controlled: OpenCL 2.1+] See module docs for more info.
+#[cfg(feature = "opencl_version_2_1")]
+pub fn set_default_device_command_queue(context: &Context, device: &DeviceId,
+            queue: &CommandQueue, device_version: Option<&OpenclVersion>) -> OclResult<()> {
+    verify_device_version(device_version, [2, 1], device)
+        .chain_err(|| "::set_default_device_command_queue")?;
+
+    match get_command_queue_info(queue, CommandQueueInfo::Properties) {
+        CommandQueueInfoResult::Properties(props) => {
+            if !props.contains(::QUEUE_ON_DEVICE) {
+                return OclError::err_string(format!("::set_default_device_command_queue: The \
+                    command queue is not an on-device queue (properties: {:?}). Create it \
+                    with 'QUEUE_ON_DEVICE' ('CommandQueueProperties::on_device').", props));
+            }
+        },
+        CommandQueueInfoResult::Error(err) => return Err(*err),
+        _ => unreachable!(),
+    }
+
+    let errcode = unsafe { ffi::clSetDefaultDeviceCommandQueue(
+        context.as_ptr(),
+        device.as_ptr(),
+        queue.as_ptr(),
+    ) };
+
+    eval_errcode(errcode, (), "clSetDefaultDeviceCommandQueue", "")
+}
+
 //============================================================================
 //============================= Context APIs  ================================
 //============================================================================
@@ -502,7 +1093,9 @@ pub unsafe fn release_device(device: &DeviceId, device_version: Option<&OpenclVe
 /// Platform is specified in `properties`. If `properties` is `None`, the platform may
 /// default to the first available.
 ///
-/// [FIXME]: Incomplete implementation. Callback and userdata untested.
+/// See `::create_context_with_callback` to receive asynchronous error
+/// reports with a Rust closure instead of `pfn_notify` and `user_data`.
+///
 /// [FIXME]: Verify OpenCL Version on property.
 /// [FIXME]: Most context sources not implemented for `ContextProperties`.
 //
@@ -554,7 +1147,10 @@ pub fn create_context<D: ClDeviceIdPtr>(properties: Option<&ContextProperties>,
     }
 
     let properties_bytes: Vec<isize> = match properties {
-        Some(props) => props.to_raw(),
+        Some(props) => {
+            props.validate().chain_err(|| "::create_context")?;
+            props.to_raw()
+        },
         None => Vec::<isize>::with_capacity(0),
     };
 
@@ -571,10 +1167,8 @@ pub fn create_context<D: ClDeviceIdPtr>(properties: Option<&ContextProperties>,
         // ptr::null() as *const cl_context_properties
     };
 
-    // [FIXME]: Disabled:
     let user_data_ptr = match user_data {
-        // Some(ud_ptr) => ud_ptr,
-        Some(_) => ptr::null_mut(),
+        Some(ud_ptr) => ud_ptr,
         None => ptr::null_mut(),
     };
 
@@ -594,6 +1188,68 @@ pub fn create_context<D: ClDeviceIdPtr>(properties: Option<&ContextProperties>,
         .map(|ctx_ptr| unsafe { Context::from_raw_create_ptr(ctx_ptr) })
 }
 
+/// Creates a new context valid for all devices in `device_ids` which calls
+/// `callback` with a description of any error reported asynchronously by
+/// the implementation, along with any implementation-specific binary data
+/// (which is often empty).
+///
+/// `callback` may be called from any thread, any number of times, until the
+/// context is destroyed by the implementation (which may be well after the
+/// last `Context` handle is dropped if queues or other objects still refer
+/// to it).
+///
+/// With the `opencl_version_3_0` feature enabled and an OpenCL 3.0+
+/// platform, `callback` is dropped once the implementation reports that the
+/// context has been destroyed (`clSetContextDestructorCallback`). Otherwise
+/// the destruction of a context cannot be observed and `callback` is never
+/// dropped (it is leaked along with the small allocation holding it).
+pub fn create_context_with_callback<D>(properties: Option<&ContextProperties>,
+            device_ids: &[D], callback: ContextCallback) -> OclResult<Context>
+        where D: ClDeviceIdPtr
+{
+    // Owned by the implementation from here on (see `_context_destructor_trampoline`):
+    let user_data = Box::into_raw(Box::new(Mutex::new(callback))) as *mut c_void;
+
+    match create_context(properties, device_ids, Some(_context_callback_trampoline),
+            Some(user_data)) {
+        Ok(context) => {
+            set_context_callback_destructor(&context, user_data);
+            Ok(context)
+        },
+        Err(err) => {
+            // The context was not created, the callback will never be called:
+            unsafe { drop(Box::from_raw(user_data as *mut Mutex<ContextCallback>)); }
+            Err(err)
+        },
+    }
+}
+
+// Declared locally as `cl-sys` predates OpenCL 3.0.
+#[cfg(feature = "opencl_version_3_0")]
+extern "system" {
+    fn clSetContextDestructorCallback(context: cl_context,
+        pfn_notify: Option<extern "C" fn(cl_context, *mut c_void)>,
+        user_data: *mut c_void) -> cl_int;
+}
+
+// Arranges for the boxed callback passed to `::create_context_with_callback`
+// (`user_data`) to be dropped once `context` is destroyed, if the platform
+// supports it. The callback is leaked otherwise.
+#[cfg(feature = "opencl_version_3_0")]
+fn set_context_callback_destructor(context: &Context, user_data: *mut c_void) {
+    match context.platform_version() {
+        Ok(ver) if ver >= OpenclVersion::new(3, 0) => (),
+        _ => return,
+    }
+
+    // Ignore errors, leaking the callback:
+    let _ = unsafe { clSetContextDestructorCallback(context.as_ptr(),
+        Some(_context_destructor_trampoline), user_data) };
+}
+
+#[cfg(not(feature = "opencl_version_3_0"))]
+fn set_context_callback_destructor(_: &Context, _: *mut c_void) {}
+
 /// Creates a new context pointer for all devices of a specific type.
 ///
 /// Platform is specified in `properties`. If `properties` is `None`, the platform may
@@ -625,7 +1281,10 @@ pub fn create_context_from_type<D: ClDeviceIdPtr>(properties: Option<&ContextPro
     // }
 
     let properties_bytes: Vec<isize> = match properties {
-        Some(props) => props.to_raw(),
+        Some(props) => {
+            props.validate().chain_err(|| "::create_context_from_type")?;
+            props.to_raw()
+        },
         None => Vec::<isize>::with_capacity(0),
     };
 
@@ -642,10 +1301,8 @@ pub fn create_context_from_type<D: ClDeviceIdPtr>(properties: Option<&ContextPro
         // ptr::null() as *const cl_context_properties
     };
 
-    // [FIXME]: Disabled:
     let user_data_ptr = match user_data {
-        // Some(ud_ptr) => ud_ptr,
-        Some(_) => ptr::null_mut(),
+        Some(ud_ptr) => ud_ptr,
         None => ptr::null_mut(),
     };
 
@@ -663,6 +1320,14 @@ pub fn create_context_from_type<D: ClDeviceIdPtr>(properties: Option<&ContextPro
 }
 
 /// Increments the reference count of a context.
+///
+/// ## Safety
+///
+/// Every retain must eventually be balanced by exactly one call to
+/// `release_context`, otherwise the context will leak. Releasing more often
+/// than retaining (in addition to the release performed when a `Context` is
+/// dropped) frees the context while it may still be in use. The same
+/// applies to the other `retain_*` and `release_*` functions.
 pub unsafe fn retain_context<C>(context: C) -> OclResult<()>
         where C: ClContextPtr
 {
@@ -773,68 +1438,59 @@ pub fn get_context_platform<C>(context: C) -> OclResult<Option<PlatformId>>
 }
 
 
-/// [NOT WORKING]
+// Signature of `clGetGLContextInfoKHR` (`cl_gl.h`), which must be loaded as
+// an extension function.
+type ClGetGlContextInfoKhrFn = extern "system" fn(*const cl_context_properties,
+    cl_gl_context_info, size_t, *mut c_void, *mut size_t) -> cl_int;
+
 /// Returns OpenGL context information.
 ///
-/// Used to query current or available devices associated with an existing
-/// OpenGL context/sharegroup.
+/// Used to query the device currently associated with
+/// (`GlContextInfo::CurrentDevice`), or all devices which may be associated
+/// with (`GlContextInfo::Devices`), an existing OpenGL context or share
+/// group, such as before creating a context with `::create_context`.
 ///
 /// `properties` must identify a single valid GL context or GL share group
-/// object and a valid platform.
-///
-/// ### Debugging (notes)
-///
-/// For some reason, calling the function pointer returned by the call to
-/// `ffi::clGetExtensionFunctionAddressForPlatform` causes a segfault
-/// (`ffi::clGetExtensionFunctionAddressForPlatform` appears to be working
-/// just fine). The function pointer returned is not null.
-///
-/// Further investigation into the what address the returned function pointer
-/// points to is needed. There may be some Rust-specific quirk having to do
-/// with how libraries are loaded into memory. There may alternatively just be
-/// a simple mistake somewhere.
-///
+/// object and a valid platform. `clGetGLContextInfoKHR` is resolved with
+/// `::get_extension_function_address_for_platform` and so requires a 1.2+
+/// platform supporting the `cl_khr_gl_sharing` extension.
 pub fn get_gl_context_info_khr(properties: &ContextProperties, request: GlContextInfo)
         -> GlContextInfoResult
 {
-    let cl_get_gl_context_info_khr_fn = unsafe {
-        let fn_name = match ::std::ffi::CString::new("clGetGLContextInfoKHR") {
-            Ok(s) => s,
-            Err(err) => return GlContextInfoResult::Error(Box::new(err.into())),
-        };
-
-        let plat = match properties.get_platform() {
-            Some(p) => p,
-            None => {
-                return GlContextInfoResult::Error(Box::new("ocl::core::get_gl_context_info_khr: \
-                    Context properties must specify a platform.".into()));
-            },
-        };
+    let platform = match properties.get_platform() {
+        Some(p) => p,
+        None => {
+            return GlContextInfoResult::Error(Box::new("::get_gl_context_info_khr: \
+                Context properties must specify a platform.".into()));
+        },
+    };
 
-        let fn_ptr = ffi::clGetExtensionFunctionAddressForPlatform(plat.as_ptr(),
-            fn_name.as_ptr() as *mut _);
+    if let Err(err) = properties.validate() {
+        return GlContextInfoResult::Error(Box::new(err.chain("::get_gl_context_info_khr")));
+    }
 
-        if fn_ptr.is_null() {
-            return GlContextInfoResult::Error(Box::new("Unable to get extension function \
-                address for clGetGLContextInfoKHR. The function is not supported by this \
-                platform.".into()));
+    // The address returned is that of the function itself, not a pointer to
+    // a function pointer:
+    let get_gl_context_info_fn: ClGetGlContextInfoKhrFn = unsafe {
+        match get_extension_function_address_for_platform(&platform, "clGetGLContextInfoKHR",
+                None) {
+            Ok(fn_ptr) => mem::transmute::<*mut c_void, ClGetGlContextInfoKhrFn>(fn_ptr),
+            Err(err) => return GlContextInfoResult::Error(Box::new(err)),
         }
-
-        fn_ptr as ffi::clGetGLContextInfoKHR_fn
     };
 
-    let props_bytes = properties.to_raw();
+    let props_raw = properties.to_raw();
     let mut result_size: size_t = 0;
 
-    let errcode = unsafe { (*cl_get_gl_context_info_khr_fn)(
-        props_bytes.as_ptr(),
+    let errcode = get_gl_context_info_fn(
+        props_raw.as_ptr(),
         request as cl_gl_context_info,
         0 as size_t,
         0 as *mut c_void,
-        &mut result_size as *mut usize,
-    ) };
+        &mut result_size as *mut size_t,
+    );
 
-    if let Err(err) = eval_errcode(errcode, (), "clGetGlContextInfoKhr", "") {
+    if let Err(err) = eval_errcode(errcode, (), "clGetGLContextInfoKHR", "") {
         return GlContextInfoResult::Error(Box::new(err));
     }
 
@@ -842,23 +1498,17 @@ pub fn get_gl_context_info_khr(properties: &ContextProperties, request: GlContex
         return GlContextInfoResult::from_bytes(request, Ok(vec![]));
     }
 
-    // // DEBUG:
-    // let result_size = match request {
-    //     GlContextInfo::CurrentDevice => ::std::mem::size_of::<ffi::cl_device_id>(),
-    //     GlContextInfo::Devices => ::std::mem::size_of::<*mut ffi::cl_device_id>(),
-    // };
-
     let mut result: Vec<u8> = iter::repeat(0).take(result_size).collect();
 
-    let errcode = unsafe { (*cl_get_gl_context_info_khr_fn)(
-        props_bytes.as_ptr(),
+    let errcode = get_gl_context_info_fn(
+        props_raw.as_ptr(),
         request as cl_gl_context_info,
         result_size as size_t,
         result.as_mut_ptr() as *mut c_void,
-        0 as *mut usize,
-    ) };
+        0 as *mut size_t,
+    );
 
-    let result = eval_errcode(errcode, result, "clGetGlContextInfoKhr", "");
+    let result = eval_errcode(errcode, result, "clGetGLContextInfoKHR", "");
     GlContextInfoResult::from_bytes(request, result)
 }
 
@@ -885,9 +1535,27 @@ pub fn create_command_queue<C, D>(
 
     let mut errcode: cl_int = 0;
 
-    let cq_ptr = unsafe { ffi::clCreateCommandQueue(
-        context.as_ptr(),
-        device.as_ptr(),
+    // On-device queues can only be created with the 2.0 API:
+    #[cfg(feature = "opencl_version_2_0")]
+    {
+        if cmd_queue_props & ::QUEUE_ON_DEVICE.bits() != 0 {
+            let props_raw: [ffi::cl_queue_properties; 3] =
+                [ffi::CL_QUEUE_PROPERTIES as ffi::cl_queue_properties, cmd_queue_props, 0];
+
+            let cq_ptr = unsafe { ffi::clCreateCommandQueueWithProperties(
+                context.as_ptr(),
+                device.as_ptr(),
+                props_raw.as_ptr(),
+                &mut errcode
+            ) };
+            return eval_errcode(errcode, cq_ptr, "clCreateCommandQueueWithProperties", "")
+                .map(|cq_ptr| unsafe { CommandQueue::from_raw_create_ptr(cq_ptr) });
+        }
+    }
+
+    let cq_ptr = unsafe { ffi::clCreateCommandQueue(
+        context.as_ptr(),
+        device.as_ptr(),
         cmd_queue_props,
         &mut errcode
     ) };
@@ -896,22 +1564,42 @@ pub fn create_command_queue<C, D>(
 
 }
 
-/// Increments the reference count of a command queue.
+/// Increments the reference count of a command queue. Must be balanced by a
+/// call to `release_command_queue` (see `retain_context`).
 pub unsafe fn retain_command_queue(queue: &CommandQueue) -> OclResult<()> {
     eval_errcode(ffi::clRetainCommandQueue(queue.as_ptr()), (), "clRetainCommandQueue", "")
 }
 
 /// Decrements the reference count of a command queue.
-///
-/// [FIXME]: Return result
 pub unsafe fn release_command_queue(queue: &CommandQueue) -> OclResult<()> {
     eval_errcode(ffi::clReleaseCommandQueue(queue.as_ptr()), (), "clReleaseCommandQueue", "")
 }
 
 /// Returns information about a command queue
+///
+/// `CommandQueueInfo::Size` is [Version Controlled: OpenCL 2.0+] and is only
+/// valid for on-device queues. See module docs for more info.
 pub fn get_command_queue_info(queue: &CommandQueue, request: CommandQueueInfo,
         ) -> CommandQueueInfoResult
 {
+    if let CommandQueueInfo::Size = request {
+        let device = match get_command_queue_info(queue, CommandQueueInfo::Device) {
+            CommandQueueInfoResult::Device(device) => device,
+            CommandQueueInfoResult::Error(err) => return CommandQueueInfoResult::Error(err),
+            _ => unreachable!(),
+        };
+
+        let device_version = match get_device_info(device, DeviceInfo::Version) {
+            DeviceInfoResult::Version(ver) => ver,
+            DeviceInfoResult::Error(err) => return CommandQueueInfoResult::Error(err),
+            _ => unreachable!(),
+        };
+
+        if let Err(err) = verify_versions(&[device_version], [2, 0]) {
+            return CommandQueueInfoResult::Error(Box::new(err));
+        }
+    }
+
     let mut result_size: size_t = 0;
 
     let errcode = unsafe { ffi::clGetCommandQueueInfo(
@@ -951,6 +1639,10 @@ pub fn get_command_queue_info(queue: &CommandQueue, request: CommandQueueInfo,
 //============================================================================
 
 /// Returns a new buffer pointer with size (bytes): `len` * sizeof(T).
+///
+/// When `flags` contains `MEM_COPY_HOST_PTR` or `MEM_USE_HOST_PTR`, `data`
+/// must be present and contain at least `len` elements. A conversion error
+/// is returned otherwise.
 pub unsafe fn create_buffer<C, T>(
             context: C,
             flags: MemFlags,
@@ -961,17 +1653,25 @@ pub unsafe fn create_buffer<C, T>(
 {
     // Verify that the context is valid:
     try!(verify_context(context));
+    flags.validate().chain_err(|| "::create_buffer")?;
 
     let mut errcode: cl_int = 0;
 
     let host_ptr = match data {
         Some(d) => {
-            if d.len() != len {
-                return OclError::err_string("ocl::create_buffer(): Data length mismatch.");
+            if d.len() < len {
+                return OclError::err_conversion(format!("::create_buffer: Host data length \
+                    ({}) is less than the buffer length ({}).", d.len(), len));
             }
             d.as_ptr() as cl_mem
         },
-        None => ptr::null_mut(),
+        None => {
+            if flags.intersects(::MEM_COPY_HOST_PTR | ::MEM_USE_HOST_PTR) {
+                return OclError::err_conversion("::create_buffer: Host data must be provided \
+                    when 'MEM_COPY_HOST_PTR' or 'MEM_USE_HOST_PTR' is specified.");
+            }
+            ptr::null_mut()
+        },
     };
 
     let buf_ptr = ffi::clCreateBuffer(
@@ -984,11 +1684,130 @@ pub unsafe fn create_buffer<C, T>(
 
     // [TODO]: Convert back the return style to this:
     eval_errcode(errcode, buf_ptr, "clCreateBuffer", "")
-        .map(|ptr| Mem::from_raw_create_ptr(ptr))
+        .map(|ptr| Mem::from_raw_create_ptr_tagged(ptr, MemObjectType::Buffer, flags))
+}
+
+/// Returns a new buffer containing a copy of `data`.
+///
+/// `MEM_COPY_HOST_PTR` is added to `flags` and the buffer length is taken
+/// from `data`, so the contents are available as soon as this function
+/// returns without a separate write. `flags` may not contain
+/// `MEM_USE_HOST_PTR` and `data` may not be empty.
+pub fn create_buffer_from_slice<C, T>(context: C, flags: MemFlags, data: &[T])
+        -> OclResult<Mem>
+        where C: ClContextPtr, T: OclPrm
+{
+    if flags.contains(::MEM_USE_HOST_PTR) {
+        return OclError::err_conversion("::create_buffer_from_slice: 'MEM_USE_HOST_PTR' may \
+            not be specified. The data is always copied ('MEM_COPY_HOST_PTR').");
+    }
+
+    if data.is_empty() {
+        return OclError::err_conversion("::create_buffer_from_slice: The data slice is empty.");
+    }
+
+    unsafe {
+        create_buffer(context, flags | ::MEM_COPY_HOST_PTR, data.len(), Some(data))
+            .chain_err(|| "::create_buffer_from_slice")
+    }
+}
+
+/// Returns a new buffer of `len` elements allocated from host-accessible
+/// memory (`MEM_ALLOC_HOST_PTR` is added to `flags`).
+///
+/// This is the zero-copy path: rather than reading and writing with
+/// explicit transfers, map the buffer (see `::map_buffer`), access it
+/// through the mapped pointer, then unmap it before use by a kernel. On
+/// devices which share physical memory with the host (CPUs and most
+/// integrated GPUs) mapping involves no copy at all. `flags` may not contain
+/// `MEM_USE_HOST_PTR` or `MEM_COPY_HOST_PTR`.
+pub fn create_host_buffer<C, T>(context: C, flags: MemFlags, len: usize) -> OclResult<Mem>
+        where C: ClContextPtr, T: OclPrm
+{
+    if flags.intersects(::MEM_USE_HOST_PTR | ::MEM_COPY_HOST_PTR) {
+        return OclError::err_conversion("::create_host_buffer: 'MEM_USE_HOST_PTR' and \
+            'MEM_COPY_HOST_PTR' may not be specified. Map the buffer to initialize it.");
+    }
+
+    unsafe {
+        create_buffer::<C, T>(context, flags | ::MEM_ALLOC_HOST_PTR, len, None)
+            .chain_err(|| "::create_host_buffer")
+    }
+}
+
+/// Creates a new pipe able to hold `max_packets` packets of `packet_size`
+/// bytes each.
+///
+/// Pipes are memory objects which can only be passed to kernels (as `pipe`
+/// arguments). They cannot be read, written, or mapped by the host. `flags`
+/// may only contain `MEM_READ_WRITE` and `MEM_HOST_NO_ACCESS`.
+///
+/// [Version Controlled: OpenCL 2.0+] See module docs for more info.
+#[cfg(feature = "opencl_version_2_0")]
+pub fn create_pipe<C>(context: C, flags: MemFlags, packet_size: u32, max_packets: u32,
+            device_versions: Option<&[OpenclVersion]>) -> OclResult<Mem>
+        where C: ClContextPtr
+{
+    verify_device_versions(device_versions, [2, 0], &context.as_ptr())
+        .chain_err(|| "::create_pipe")?;
+
+    let mut errcode: cl_int = 0;
+
+    let pipe_ptr = unsafe { ffi::clCreatePipe(
+        context.as_ptr(),
+        flags.bits() as cl_mem_flags,
+        packet_size,
+        max_packets,
+        ptr::null(),
+        &mut errcode,
+    ) };
+
+    eval_errcode(errcode, pipe_ptr, "clCreatePipe", "")
+        .map(|ptr| unsafe { Mem::from_raw_create_ptr_tagged(ptr, MemObjectType::Pipe, flags) })
+}
+
+/// Returns the packet size or the maximum number of packets of a pipe
+/// created with `::create_pipe`.
+///
+/// [Version Controlled: OpenCL 2.0+] See module docs for more info.
+#[cfg(feature = "opencl_version_2_0")]
+pub fn get_pipe_info(pipe: &Mem, request: ::PipeInfo) -> OclResult<u32> {
+    match pipe.mem_type() {
+        Ok(MemObjectType::Pipe) => (),
+        Ok(mem_type) => return OclError::err_string(format!("::get_pipe_info: Invalid memory \
+            object type: '{:?}'. Must be a pipe.", mem_type)),
+        Err(err) => return Err(err).chain_err(|| "::get_pipe_info"),
+    }
+
+    let mut result: u32 = 0;
+
+    let errcode = unsafe { ffi::clGetPipeInfo(
+        pipe.as_ptr(),
+        request as cl_uint,
+        mem::size_of::<u32>(),
+        &mut result as *mut u32 as *mut c_void,
+        ptr::null_mut(),
+    ) };
+
+    eval_errcode(errcode, result, "clGetPipeInfo", "")
 }
 
 /// [UNTESTED]
 /// Return a buffer pointer from a `OpenGL` buffer object.
+///
+/// `gl_object` is the name of a GL buffer object (such as a VBO) which has
+/// already been created with `glBufferData`.
+///
+/// The context must have been created with the properties required for
+/// OpenGL sharing (see `ContextProperties::gl_context` and the platform
+/// specific display/share group properties) and the GL context must be
+/// current. An error status (such as `CL_INVALID_CONTEXT` or
+/// `CL_INVALID_GL_OBJECT`) is returned otherwise.
+///
+/// The resulting buffer must be acquired with `::enqueue_acquire_gl_objects`
+/// before use and released with `::enqueue_release_gl_objects` afterwards.
+///
+/// [SDK Docs](https://www.khronos.org/registry/cl/sdk/1.2/docs/man/xhtml/clCreateFromGLBuffer.html)
 pub unsafe fn create_from_gl_buffer<C>(
             context: C,
             gl_object: cl_GLuint,
@@ -1008,11 +1827,19 @@ pub unsafe fn create_from_gl_buffer<C>(
             &mut errcode);
 
     eval_errcode(errcode, buf_ptr, "clCreateFromGLBuffer", "")
-        .map(|ptr| Mem::from_raw_create_ptr(ptr))
+        .map(|ptr| Mem::from_raw_create_ptr_tagged(ptr, MemObjectType::Buffer, flags))
 }
 
-/// [UNTESTED]
-/// Return a renderbuffer pointer from a `OpenGL` renderbuffer object.
+/// Creates an image object from an OpenGL renderbuffer object.
+///
+/// `context` must have been created with the GL sharing properties
+/// (`ContextProperties::gl_context` along with the platform) and
+/// `renderbuffer` must name an existing, color-renderable renderbuffer with
+/// storage already allocated. Depth and stencil renderbuffers are rejected by
+/// the implementation.
+///
+/// The returned image must be acquired with `::enqueue_acquire_gl_objects`
+/// before use in any command.
 pub unsafe fn create_from_gl_renderbuffer<C>(
             context: C,
             renderbuffer: cl_GLuint,
@@ -1032,13 +1859,21 @@ pub unsafe fn create_from_gl_renderbuffer<C>(
             &mut errcode);
 
     eval_errcode(errcode, buf_ptr, "clCreateFromGLRenderbuffer", "")
-        .map(|ptr| Mem::from_raw_create_ptr(ptr))
+        .map(|ptr| Mem::from_raw_create_ptr_tagged(ptr, MemObjectType::Image2d, flags))
 }
 
 /// [UNTESTED]
-/// Return a texture2D pointer from a `OpenGL` texture2D object.
+/// Return an image pointer from a `OpenGL` texture object.
 ///
-/// [TODO]: If version is < 1.2, automatically use older versions.
+/// The context must have been created with OpenGL sharing enabled. The
+/// target and mipmap level of the resulting image can be read back with
+/// `clGetGLTextureInfo`.
+///
+/// On devices older than OpenCL 1.2, `GL_TEXTURE_3D` targets are forwarded
+/// to `::create_from_gl_texture_3d` and all others to
+/// `::create_from_gl_texture_2d`.
+///
+/// [SDK Docs](https://www.khronos.org/registry/cl/sdk/1.2/docs/man/xhtml/clCreateFromGLTexture.html)
 ///
 /// [Version Controlled: OpenCL 1.2+] See module docs for more info.
 pub unsafe fn create_from_gl_texture<C>(
@@ -1054,26 +1889,20 @@ pub unsafe fn create_from_gl_texture<C>(
     // Verify that the context is valid
     try!(verify_context(context));
 
-    // Verify device versions:
-    verify_device_versions(device_versions, [1, 2], &context.as_ptr())
-        .chain_err(|| "::create_from_gl_texture")?;;
-
-    // [TODO]: Forward old versions to these:
-    // let obj_core = match image_desc.image_depth {
-    //     2 => unsafe { try!(core::create_from_gl_texture_2d(
-    //                         queue.context_core(),
-    //                         texture_target,
-    //                         miplevel,
-    //                         texture,
-    //                         flags)) },
-    //     3 => unsafe { try!(core::create_from_gl_texture_3d(
-    //                         queue.context_core(),
-    //                         texture_target,
-    //                         miplevel,
-    //                         texture,
-    //                         flags)) },
-    //     _ => unimplemented!() // FIXME: return an error ? or panic! ?
-    // };
+    // Verify device versions, forwarding to the deprecated 1.1 functions if
+    // necessary:
+    if let Err(err) = verify_device_versions(device_versions, [1, 2], &context.as_ptr()) {
+        return match *err.kind() {
+            OclErrorKind::VersionLow { .. } => {
+                if texture_target == ffi::GL_TEXTURE_3D as cl_GLenum {
+                    create_from_gl_texture_3d(context, texture_target, miplevel, texture, flags)
+                } else {
+                    create_from_gl_texture_2d(context, texture_target, miplevel, texture, flags)
+                }
+            },
+            _ => Err::<Mem, _>(err).chain_err(|| "::create_from_gl_texture"),
+        };
+    }
 
     let mut errcode: cl_int = 0;
 
@@ -1085,8 +1914,9 @@ pub unsafe fn create_from_gl_texture<C>(
             texture,
             &mut errcode);
 
+    // The image type depends on the texture target so is queried:
     eval_errcode(errcode, buf_ptr, "clCreateFromGLTexture", "")
-        .map(|ptr| Mem::from_raw_create_ptr(ptr))
+        .map(|ptr| Mem::from_raw_create_ptr(ptr).with_queried_info())
 }
 
 /// [UNTESTED] [DEPRICATED]
@@ -1114,7 +1944,7 @@ pub unsafe fn create_from_gl_texture_2d<C>(
             &mut errcode);
 
     eval_errcode(errcode, buf_ptr, "clCreateFromGLTexture2D", "")
-        .map(|ptr| Mem::from_raw_create_ptr(ptr))
+        .map(|ptr| Mem::from_raw_create_ptr_tagged(ptr, MemObjectType::Image2d, flags))
 }
 
 /// [UNTESTED] [DEPRICATED]
@@ -1142,7 +1972,62 @@ pub unsafe fn create_from_gl_texture_3d<C>(
             &mut errcode);
 
     eval_errcode(errcode, buf_ptr, "clCreateFromGLTexture3D", "")
-        .map(|ptr| Mem::from_raw_create_ptr(ptr))
+        .map(|ptr| Mem::from_raw_create_ptr_tagged(ptr, MemObjectType::Image3d, flags))
+}
+
+/// Returns the type of the OpenGL object from which `mem` was created along
+/// with the object's GL name.
+pub fn get_gl_object_info(mem: &Mem) -> OclResult<(ClGlObjectType, cl_GLuint)> {
+    let mut object_type: ffi::cl_gl_object_type = 0;
+    let mut object_name: cl_GLuint = 0;
+
+    let errcode = unsafe { ffi::clGetGLObjectInfo(
+        mem.as_ptr(),
+        &mut object_type,
+        &mut object_name,
+    ) };
+    eval_errcode(errcode, (), "clGetGLObjectInfo", "")?;
+
+    match ClGlObjectType::from_u32(object_type as u32) {
+        Some(ot) => Ok((ot, object_name)),
+        None => OclError::err_conversion(format!("::get_gl_object_info: Unknown OpenGL object \
+            type: {:#X}.", object_type)),
+    }
+}
+
+/// Returns the texture target or mipmap level of the OpenGL texture from
+/// which `mem` was created (see `::create_from_gl_texture`).
+pub fn get_gl_texture_info(mem: &Mem, request: ::GlTextureInfo) -> GlTextureInfoResult {
+    let mut result_size: size_t = 0;
+
+    let errcode = unsafe { ffi::clGetGLTextureInfo(
+        mem.as_ptr(),
+        request as ffi::cl_gl_texture_info,
+        0 as size_t,
+        0 as *mut c_void,
+        &mut result_size as *mut size_t,
+    ) };
+
+    if let Err(err) = eval_errcode(errcode, (), "clGetGLTextureInfo", "") {
+        return GlTextureInfoResult::Error(Box::new(err));
+    }
+
+    if result_size == 0 {
+        return GlTextureInfoResult::from_bytes(request, Ok(vec![]));
+    }
+
+    let mut result: Vec<u8> = iter::repeat(0u8).take(result_size).collect();
+
+    let errcode = unsafe { ffi::clGetGLTextureInfo(
+        mem.as_ptr(),
+        request as ffi::cl_gl_texture_info,
+        result_size as size_t,
+        result.as_mut_ptr() as *mut _ as *mut c_void,
+        0 as *mut size_t,
+    ) };
+
+    let result = eval_errcode(errcode, result, "clGetGLTextureInfo", "");
+    GlTextureInfoResult::from_bytes(request, result)
 }
 
 /// Creates a new buffer object (referred to as a sub-buffer object) from an
@@ -1171,8 +2056,10 @@ pub fn create_sub_buffer<T: OclPrm>(
         &mut errcode,
     ) };
 
+    // Host access flags not specified are inherited from the parent buffer
+    // so are queried (once) rather than recorded:
     eval_errcode(errcode, sub_buf_ptr, "clCreateSubBuffer", "")
-        .map(|ptr| unsafe { Mem::from_raw_create_ptr(ptr) })
+        .map(|ptr| unsafe { Mem::from_raw_create_ptr(ptr).with_queried_info() })
 }
 
 /// Returns a new image (mem) pointer.
@@ -1196,13 +2083,20 @@ pub unsafe fn create_image<C, T>(
     // Verify device versions:
     verify_device_versions(device_versions, [1, 2], &context.as_ptr())
         .chain_err(|| "::create_image")?;
+    flags.validate().chain_err(|| "::create_image")?;
 
     let mut errcode: cl_int = 0;
 
     let host_ptr = match data {
         Some(d) => {
-            // [FIXME]: CALCULATE CORRECT IMAGE SIZE AND COMPARE WITH FORMAT/DESC
-            // assert!(d.len() == len, "ocl::create_image(): Data length mismatch.");
+            let required_bytes = desc.host_data_bytes(format.bytes_per_pixel());
+            let data_bytes = d.len() * mem::size_of::<T>();
+
+            if data_bytes < required_bytes {
+                return OclError::err_string(format!("::create_image: Data length out of \
+                    bounds. The image described requires {} bytes of host data but only {} \
+                    were provided.", required_bytes, data_bytes));
+            }
             d.as_ptr() as cl_mem
         },
         None => ptr::null_mut(),
@@ -1218,10 +2112,11 @@ pub unsafe fn create_image<C, T>(
     );
 
     eval_errcode(errcode, image_ptr, "clCreateImage", "")
-        .map(|ptr| Mem::from_raw_create_ptr(ptr))
+        .map(|ptr| Mem::from_raw_create_ptr_tagged(ptr, desc.image_type, flags))
 }
 
-/// Increments the reference counter of a mem object.
+/// Increments the reference counter of a mem object. Must be balanced by a
+/// call to `release_mem_object` (see `retain_context`).
 pub unsafe fn retain_mem_object(mem: &Mem) -> OclResult<()> {
     eval_errcode(ffi::clRetainMemObject(mem.as_ptr()), (), "clRetainMemObject", "")
 }
@@ -1233,6 +2128,11 @@ pub unsafe fn release_mem_object(mem: &Mem) -> OclResult<()> {
 
 /// Returns a list of supported image formats.
 ///
+/// Returns an empty list if no formats are supported for the given
+/// combination of `flags` and `image_type`. Formats not recognized by this
+/// library are returned as `Err(ImageFormatParseError)` entries rather than
+/// failing the entire query.
+///
 /// # Example
 ///
 /// ```text
@@ -1357,12 +2257,51 @@ pub fn get_image_info(obj: &Mem, request: ImageInfo) -> ImageInfoResult {
     ImageInfoResult::from_bytes(request, result)
 }
 
-/// [UNIMPLEMENTED]
-pub fn set_mem_object_destructor_callback() -> OclResult<()> {
-    // ffi::clSetMemObjectDestructorCallback(memobj: cl_mem,
-    //                                     pfn_notify: extern fn (cl_mem, *mut c_void),
-    //                                     user_data: *mut c_void) -> cl_int;
-    unimplemented!();
+/// Registers a closure to be called once, when the memory object `mem` is
+/// actually destroyed.
+///
+/// The callback is invoked after the reference count of the underlying
+/// `cl_mem` drops to zero (whether through `::release_mem_object` or by
+/// dropping the last `Mem`) and before the resources it uses, such as a host
+/// pointer passed with `MEM_USE_HOST_PTR`, are freed or reused. Because
+/// other references may be held internally by the implementation (by
+/// enqueued commands, for example), the callback may fire some time after
+/// the final release returns and possibly on another thread.
+///
+/// Multiple callbacks registered on the same object are called in the
+/// reverse order of registration.
+///
+/// [SDK Docs](https://www.khronos.org/registry/cl/sdk/1.2/docs/man/xhtml/clSetMemObjectDestructorCallback.html)
+///
+/// [Version Controlled: OpenCL 1.1+] See module docs for more info.
+pub fn set_mem_object_destructor_callback<F>(mem: &Mem, callback: F,
+            device_version: Option<&OpenclVersion>) -> OclResult<()>
+        where F: FnOnce() + Send + 'static
+{
+    // Verify device version:
+    match device_version {
+        Some(dv) => verify_versions(&[*dv], [1, 1]),
+        None => match get_mem_object_info(mem, MemInfo::Context) {
+            MemInfoResult::Context(context) => context.verify_device_versions([1, 1]),
+            MemInfoResult::Error(err) => Err(*err),
+            _ => unreachable!(),
+        },
+    }.chain_err(|| "::set_mem_object_destructor_callback")?;
+
+    let user_data = Box::into_raw(Box::new(callback));
+
+    let errcode = unsafe { ffi::clSetMemObjectDestructorCallback(
+        mem.as_ptr(),
+        Some(_mem_destructor_trampoline::<F>),
+        user_data as *mut c_void,
+    ) };
+
+    // The callback will never be called, reclaim and drop the box:
+    if errcode != Status::CL_SUCCESS as i32 {
+        unsafe { drop(Box::from_raw(user_data)); }
+    }
+
+    eval_errcode(errcode, (), "clSetMemObjectDestructorCallback", "")
 }
 
 //============================================================================
@@ -1389,7 +2328,67 @@ pub fn create_sampler<C>(context: C, normalize_coords: bool, addressing_mode: Ad
     eval_errcode(errcode, sampler, "clCreateSampler", "")
 }
 
-/// Increments a sampler reference counter.
+/// Creates and returns a new sampler object using a list of properties.
+///
+/// If any mipmap property (mip filter mode or LOD range) is specified, every
+/// device associated with `context` must support the `cl_khr_mipmap_image`
+/// extension.
+///
+/// On pre-2.0 devices, falls back to `::create_sampler` as long as only the
+/// normalized coordinates, addressing mode, and filter mode properties are
+/// specified.
+///
+/// [Version Controlled: OpenCL 2.0+] See module docs for more info.
+#[cfg(feature = "opencl_version_2_0")]
+pub fn create_sampler_with_properties<C>(context: C, properties: &::SamplerProperties,
+            device_versions: Option<&[OpenclVersion]>) -> OclResult<Sampler>
+        where C: ClContextPtr
+{
+    if let Err(err) = verify_device_versions(device_versions, [2, 0], &context.as_ptr()) {
+        let is_version_low = match *err.kind() {
+            OclErrorKind::VersionLow { .. } => true,
+            _ => false,
+        };
+
+        return match properties.to_create_sampler_args() {
+            Some((normalize_coords, addressing_mode, filter_mode)) if is_version_low => {
+                create_sampler(context, normalize_coords, addressing_mode, filter_mode)
+            },
+            _ => Err::<Sampler, _>(err).chain_err(|| "::create_sampler_with_properties"),
+        };
+    }
+
+    if properties.uses_mipmaps() {
+        let devices = match get_context_info(context.as_ptr(), ContextInfo::Devices) {
+            ContextInfoResult::Devices(ds) => ds,
+            ContextInfoResult::Error(err) => return Err(*err),
+            _ => unreachable!(),
+        };
+
+        for device in devices {
+            if !device_has_extension(&device, "cl_khr_mipmap_image")? {
+                return OclError::err_string(format!("::create_sampler_with_properties: \
+                    Mipmap sampler properties (mip filter mode, LOD min/max) were \
+                    specified but the device, '{:?}', does not support the \
+                    'cl_khr_mipmap_image' extension.", device));
+            }
+        }
+    }
+
+    let props = properties.to_raw();
+    let mut errcode = 0;
+
+    let sampler = unsafe { Sampler::from_raw_create_ptr(ffi::clCreateSamplerWithProperties(
+        context.as_ptr(),
+        props.as_ptr() as *const ffi::cl_sampler_properties,
+        &mut errcode,
+    )) };
+
+    eval_errcode(errcode, sampler, "clCreateSamplerWithProperties", "")
+}
+
+/// Increments a sampler reference counter. Must be balanced by a call to
+/// `release_sampler` (see `retain_context`).
 pub unsafe fn retain_sampler(sampler: &Sampler) -> OclResult<()> {
     eval_errcode(ffi::clRetainSampler(sampler.as_ptr()), (), "clRetainSampler", "")
 }
@@ -1473,6 +2472,45 @@ pub fn create_program_with_source<C>(
         .map(|ptr| unsafe { Program::from_raw_create_ptr(ptr) })
 }
 
+/// Creates a new program from several source strings, such as the contents
+/// of multiple `.cl` files, without copying or NUL-terminating them.
+///
+/// Each string is passed to the compiler as a separate source so that
+/// diagnostics refer to the string they originate from. Functions defined in
+/// one string are visible to those which follow.
+pub fn create_program_with_sources<C>(context: C, sources: &[&str]) -> OclResult<Program>
+        where C: ClContextPtr
+{
+    if sources.is_empty() {
+        return OclError::err_string("::create_program_with_sources: No sources specified.");
+    }
+
+    // A length of zero would cause the string to be treated as NUL-terminated:
+    if let Some(idx) = sources.iter().position(|src| src.is_empty()) {
+        return OclError::err_string(format!("::create_program_with_sources: Source \
+            string [{}] is empty.", idx));
+    }
+
+    try!(verify_context(context));
+
+    let lens: Vec<usize> = sources.iter().map(|src| src.len()).collect();
+    let ptrs: Vec<*const c_char> = sources.iter().map(|src| src.as_ptr() as *const c_char)
+        .collect();
+
+    let mut errcode: cl_int = 0;
+
+    let program_ptr = unsafe { ffi::clCreateProgramWithSource(
+        context.as_ptr(),
+        ptrs.len() as cl_uint,
+        ptrs.as_ptr(),
+        lens.as_ptr(),
+        &mut errcode,
+    ) };
+
+    eval_errcode(errcode, program_ptr, "clCreateProgramWithSource", "")
+        .map(|ptr| unsafe { Program::from_raw_create_ptr(ptr) })
+}
+
 /// [UNTESTED]
 /// Creates a program object for a context, and loads the binary bits
 /// specified by binary into the program object.
@@ -1494,6 +2532,7 @@ pub fn create_program_with_binary<C, D>(
         Length of 'devices' must equal the length of 'binaries' (e.g. one binary per device)."); }
 
     let lengths: Vec<usize> = binaries.iter().map(|bin| bin.len()).collect();
+    let binary_ptrs: Vec<*const u8> = binaries.iter().map(|bin| bin.as_ptr()).collect();
     let mut binary_status: Vec<i32> = iter::repeat(0).take(devices.len()).collect();
     let mut errcode: cl_int = 0;
 
@@ -1502,7 +2541,7 @@ pub fn create_program_with_binary<C, D>(
         devices.len() as u32,
         devices.as_ptr() as *const _ as *const cl_device_id,
         lengths.as_ptr(),
-        binaries.as_ptr() as *const *const u8,
+        binary_ptrs.as_ptr(),
         binary_status.as_mut_ptr(),
         &mut errcode,
     ) };
@@ -1516,6 +2555,23 @@ pub fn create_program_with_binary<C, D>(
     unsafe { Ok(Program::from_raw_create_ptr(program)) }
 }
 
+/// Creates a program object for a context, loading the same `binary` for
+/// every device in `devices`.
+///
+/// Equivalent to calling `::create_program_with_binary` with `binary`
+/// repeated once per device. Errors still report the status of each device
+/// individually.
+pub fn create_program_with_binary_broadcast<C, D>(
+            context: C,
+            devices: &[D],
+            binary: &[u8],
+        ) -> OclResult<Program>
+        where C: ClContextPtr, D: ClDeviceIdPtr
+{
+    let binaries: Vec<&[u8]> = iter::repeat(binary).take(devices.len()).collect();
+    create_program_with_binary(context, devices, &binaries)
+}
+
 /// [UNIMPLEMENTED]
 ///
 /// [Version Controlled: OpenCL 1.2+] See module docs for more info.
@@ -1541,7 +2597,7 @@ pub fn create_program_with_il<C>(
         ) -> OclResult<Program>
         where C: ClContextPtr
 {
-    verify_device_versions(device_versions, [2, 1], &context)
+    verify_device_versions(device_versions, [2, 1], &context.as_ptr())
         .chain_err(|| "::create_program_with_il")?;
 
     let mut errcode: cl_int = 0;
@@ -1557,14 +2613,15 @@ pub fn create_program_with_il<C>(
         .map(|ptr| unsafe { Program::from_raw_create_ptr(ptr) })
 }
 
-/// Increments a program reference counter.
+/// Increments a program reference counter. Must be balanced by a call to
+/// `release_program` (see `retain_context`).
 pub unsafe fn retain_program(program: &Program) -> OclResult<()> {
     eval_errcode(ffi::clRetainProgram(program.as_ptr()), (), "clRetainProgram", "")
 }
 
 /// Decrements a program reference counter.
 pub unsafe fn release_program(program: &Program) -> OclResult<()> {
-    eval_errcode(ffi::clReleaseProgram(program.as_ptr()), (), "clReleaseKernel", "")
+    eval_errcode(ffi::clReleaseProgram(program.as_ptr()), (), "clReleaseProgram", "")
 }
 
 pub struct UserDataPh(usize);
@@ -1625,6 +2682,135 @@ pub fn build_program<D: ClDeviceIdPtr>(
     }
 }
 
+/// Builds a program, returning an error with the `ErrorKind::TimedOut` kind
+/// if the build has not finished within `timeout`.
+///
+/// The build is started asynchronously (by way of a completion callback) and
+/// the calling thread blocks until it is notified or the timeout elapses.
+/// OpenCL provides no way to cancel a build: after a timeout the build may
+/// still be running and `program` must not be used until it finishes (see
+/// `ProgramBuildInfo::BuildStatus`).
+pub fn build_program_with_timeout<D: ClDeviceIdPtr>(
+            program: &Program,
+            devices: Option<&[D]>,
+            options: &CString,
+            timeout: Duration,
+        ) -> OclResult<()>
+{
+    let (devices_len, devices_ptr) = match devices {
+        Some(dvs) => (dvs.len() as u32, dvs.as_ptr() as *const cl_device_id),
+        None => (0, ptr::null() as *const cl_device_id),
+    };
+
+    // Owned by `_build_program_notify` once the build has started, including
+    // after a timeout:
+    let pair = Arc::new((Mutex::new(false), Condvar::new()));
+    let user_data = Box::into_raw(Box::new(pair.clone())) as *mut c_void;
+
+    let errcode = unsafe { ffi::clBuildProgram(
+        program.as_ptr() as cl_program,
+        devices_len,
+        devices_ptr,
+        options.as_ptr(),
+        Some(_build_program_notify),
+        user_data,
+    ) };
+
+    if errcode == Status::CL_BUILD_PROGRAM_FAILURE as i32 {
+        // Whether the callback has been (or will be) called is unspecified.
+        // Leave `user_data` to it rather than risk a use after free. Only the
+        // small box and `pair` leak if it is never called:
+        return match devices {
+            Some(ds) => program_build_err(program, ds),
+            None => program_build_err(program, &program.devices()?),
+        };
+    } else if errcode != Status::CL_SUCCESS as i32 {
+        // The build was never started and the callback will never be called:
+        unsafe { drop(Box::from_raw(user_data as *mut Arc<(Mutex<bool>, Condvar)>)); }
+        return eval_errcode(errcode, (), "clBuildProgram", "");
+    }
+
+    let &(ref finished, ref cvar) = &*pair;
+    let deadline = Instant::now() + timeout;
+    let mut is_finished = finished.lock().unwrap();
+
+    while !*is_finished {
+        let now = Instant::now();
+        if now >= deadline {
+            return Err(OclError::timed_out(timeout));
+        }
+        is_finished = cvar.wait_timeout(is_finished, deadline - now).unwrap().0;
+    }
+
+    match devices {
+        Some(ds) => verify_program_built(program, ds),
+        None => verify_program_built(program, &program.devices()?),
+    }
+}
+
+/// Starts building `program` and returns immediately, calling `callback`
+/// with the program once the build has finished (successfully or not).
+///
+/// `callback` is called on a thread owned by the OpenCL implementation and
+/// should return quickly. Use `ProgramBuildInfo::BuildStatus` (or
+/// `ProgramBuildInfo::BuildLog`) within it to determine whether the build
+/// succeeded.
+///
+/// If the build cannot be started an error is returned and `callback` is
+/// never called. If the build fails before this function returns
+/// (`CL_BUILD_PROGRAM_FAILURE`) an error is returned and `callback` is only
+/// called if the implementation had already notified completion.
+pub fn build_program_async<D, F>(
+            program: Program,
+            devices: Option<&[D]>,
+            options: &CString,
+            callback: F,
+        ) -> OclResult<()>
+        where D: ClDeviceIdPtr, F: FnOnce(Program) + Send + 'static
+{
+    let (devices_len, devices_ptr) = match devices {
+        Some(dvs) => (dvs.len() as u32, dvs.as_ptr() as *const cl_device_id),
+        None => (0, ptr::null() as *const cl_device_id),
+    };
+
+    // Retained separately so that it remains valid for error reporting even
+    // if the callback has already run and dropped its own reference:
+    let program_ref = program.clone();
+    // One reference is kept here, the other is owned by the trampoline
+    // (`user_data`) once the build has started:
+    let state: Arc<BuildAsyncState<F>> = Arc::new(Mutex::new(Some((program, callback))));
+    let user_data = Arc::into_raw(state.clone()) as *mut c_void;
+
+    let errcode = unsafe { ffi::clBuildProgram(
+        program_ref.as_ptr() as cl_program,
+        devices_len,
+        devices_ptr,
+        options.as_ptr(),
+        Some(_build_program_async_trampoline::<F>),
+        user_data,
+    ) };
+
+    if errcode == Status::CL_BUILD_PROGRAM_FAILURE as i32 {
+        // Whether the trampoline has been (or will be) called is unspecified.
+        // Take the program and closure back (unless it already has) and drop
+        // them, leaving the trampoline's reference to it. Only the empty
+        // state leaks if it is never called:
+        let data = state.lock().ok().and_then(|mut data| data.take());
+        drop(data);
+        return match devices {
+            Some(ds) => program_build_err(&program_ref, ds),
+            None => program_build_err(&program_ref, &program_ref.devices()?),
+        };
+    } else if errcode != Status::CL_SUCCESS as i32 {
+        // The build was never started and the trampoline will never be
+        // called, reclaim its reference:
+        unsafe { drop(Arc::from_raw(user_data as *const BuildAsyncState<F>)); }
+        return eval_errcode(errcode, (), "clBuildProgram", "");
+    }
+
+    Ok(())
+}
+
 /// [UNIMPLEMENTED]
 ///
 /// [Version Controlled: OpenCL 1.2+] See module docs for more info.
@@ -1659,16 +2845,44 @@ pub fn link_program(device_version: Option<&OpenclVersion>) -> OclResult<()> {
     unimplemented!();
 }
 
-// [DISABLED DUE TO PLATFORM INCOMPATABILITY]
-// /// [UNTESTED]
-// /// Unloads a platform compiler.
-// ///
-// /// [Version Controlled: OpenCL 1.2+] See module docs for more info.
-// pub fn unload_platform_compiler(platform: &PlatformId,
-//          device_version: Option<&OpenclVersion>) -> OclResult<()> {
-//     unsafe { eval_errcode("clUnloadPlatformCompiler", "",
-//         ffi::clUnloadPlatformCompiler(platform.as_ptr())) }
-// }
+// `cl-sys` leaves `clUnloadPlatformCompiler` out of its bindings ([DISABLED
+// DUE TO PLATFORM INCOMPATABILITY]: some 1.1-only implementations do not
+// export the symbol). Declare it locally so that only builds opting into
+// OpenCL 1.2 need to link against it.
+#[cfg(feature = "opencl_version_1_2")]
+extern "system" {
+    fn clUnloadPlatformCompiler(platform: cl_platform_id) -> cl_int;
+}
+
+/// Allows the implementation to release the resources allocated by the
+/// compiler for `platform`.
+///
+/// This is a hint only. Programs which have already been built are
+/// unaffected and any later call to `build_program` (or the like) will
+/// reload the compiler automatically if necessary.
+///
+/// [Version Controlled: OpenCL 1.2+] See module docs for more info.
+#[cfg(feature = "opencl_version_1_2")]
+pub fn unload_platform_compiler(platform: &PlatformId,
+            platform_version: Option<&OpenclVersion>) -> OclResult<()>
+{
+    verify_platform_version(platform_version, [1, 2], platform)
+        .chain_err(|| "::unload_platform_compiler")?;
+
+    unsafe { eval_errcode(clUnloadPlatformCompiler(platform.as_ptr()), (),
+        "clUnloadPlatformCompiler", "") }
+}
+
+/// Allows the implementation to release the resources allocated by the
+/// compiler.
+///
+/// As with `unload_platform_compiler`, this is a hint only and the compiler
+/// will be reloaded automatically if needed.
+#[deprecated(since="0.6.1", note="Use `::unload_platform_compiler` instead.")]
+#[allow(deprecated)]
+pub fn unload_compiler() -> OclResult<()> {
+    unsafe { eval_errcode(ffi::clUnloadCompiler(), (), "clUnloadCompiler", "") }
+}
 
 /// Get program info.
 pub fn get_program_info(obj: &Program, request: ProgramInfo) -> ProgramInfoResult {
@@ -1706,13 +2920,59 @@ pub fn get_program_info(obj: &Program, request: ProgramInfo) -> ProgramInfoResul
     ProgramInfoResult::from_bytes(request, result)
 }
 
-/// Get program build info.
-pub fn get_program_build_info<D: ClDeviceIdPtr + Debug>(obj: &Program, device_obj: D,
-            request: ProgramBuildInfo) -> ProgramBuildInfoResult
-{
-    let mut result_size: size_t = 0;
+/// Returns the binaries for each device associated with `program`, in the
+/// same order as `ProgramInfo::Devices`.
+///
+/// Devices for which no binary is available yield an empty vector.
+pub fn get_program_binaries(program: &Program) -> OclResult<Vec<Vec<u8>>> {
+    let sizes = match get_program_info(program, ProgramInfo::BinarySizes) {
+        ProgramInfoResult::BinarySizes(sizes) => sizes,
+        ProgramInfoResult::Error(err) => return Err(*err),
+        _ => unreachable!(),
+    };
 
-    // println!("ocl::core::get_program_build_info(): device_obj: {:?}", device_obj);
+    let mut binaries: Vec<Vec<u8>> = sizes.iter().map(|&size| vec![0u8; size]).collect();
+    let mut binary_ptrs: Vec<*mut u8> = binaries.iter_mut().map(|bin| bin.as_mut_ptr())
+        .collect();
+
+    let errcode = unsafe { ffi::clGetProgramInfo(
+        program.as_ptr() as cl_program,
+        ProgramInfo::Binaries as cl_program_info,
+        binary_ptrs.len() * mem::size_of::<*mut u8>(),
+        binary_ptrs.as_mut_ptr() as *mut c_void,
+        0 as *mut size_t,
+    ) };
+
+    eval_errcode(errcode, binaries, "clGetProgramInfo", "::get_program_binaries")
+}
+
+/// Get program build info.
+///
+/// `ProgramBuildInfo::BinaryType` is [Version Controlled: OpenCL 1.2+] and
+/// `ProgramBuildInfo::GlobalVariableTotalSize` is [Version Controlled:
+/// OpenCL 2.0+]. See module docs for more info.
+pub fn get_program_build_info<D: ClDeviceIdPtr + Debug>(obj: &Program, device_obj: D,
+            request: ProgramBuildInfo) -> ProgramBuildInfoResult
+{
+    let required_version = match request {
+        ProgramBuildInfo::BinaryType => Some([1, 2]),
+        ProgramBuildInfo::GlobalVariableTotalSize => Some([2, 0]),
+        _ => None,
+    };
+
+    if let Some(required_version) = required_version {
+        let device_version = match get_device_info(device_obj, DeviceInfo::Version) {
+            DeviceInfoResult::Version(ver) => ver,
+            DeviceInfoResult::Error(err) => return ProgramBuildInfoResult::Error(err),
+            _ => unreachable!(),
+        };
+
+        if let Err(err) = verify_versions(&[device_version], required_version) {
+            return ProgramBuildInfoResult::Error(Box::new(err));
+        }
+    }
+
+    let mut result_size: size_t = 0;
 
     let errcode = unsafe { ffi::clGetProgramBuildInfo(
         obj.as_ptr() as cl_program,
@@ -1748,6 +3008,23 @@ pub fn get_program_build_info<D: ClDeviceIdPtr + Debug>(obj: &Program, device_ob
     ProgramBuildInfoResult::from_bytes(request, result)
 }
 
+/// Returns the build log of `program` for `device`.
+///
+/// The log is empty if the program has not been built for `device` or if
+/// the implementation had nothing to report.
+pub fn get_program_build_log<D: ClDeviceIdPtr + Debug>(program: &Program, device: D)
+        -> OclResult<String>
+{
+    match get_program_build_info(program, device, ProgramBuildInfo::BuildLog) {
+        ProgramBuildInfoResult::BuildLog(log) => Ok(log),
+        ProgramBuildInfoResult::Error(err) => match *err.kind() {
+            OclErrorKind::EmptyInfoResult(_) => Ok(String::new()),
+            _ => Err(*err).chain_err(|| "::get_program_build_log"),
+        },
+        _ => unreachable!(),
+    }
+}
+
 //============================================================================
 //========================== Kernel Object APIs ==============================
 //============================================================================
@@ -1778,7 +3055,8 @@ pub fn create_kernels_in_program() -> OclResult<()> {
     unimplemented!();
 }
 
-/// Increments a kernel reference counter.
+/// Increments a kernel reference counter. Must be balanced by a call to
+/// `release_kernel` (see `retain_context`).
 pub unsafe fn retain_kernel(kernel: &Kernel) -> OclResult<()> {
     eval_errcode(ffi::clRetainKernel(kernel.as_ptr()), (), "clRetainKernel", "")
 }
@@ -1938,6 +3216,13 @@ pub fn get_kernel_arg_info(obj: &Kernel, arg_index: u32, request: KernelArgInfo,
 }
 
 /// Get kernel work group info.
+///
+/// `CompileWorkGroupSize` and `GlobalWorkSize` are returned as `[usize; 3]`,
+/// `WorkGroupSize` and `PreferredWorkGroupSizeMultiple` as `usize`, and
+/// `LocalMemSize` and `PrivateMemSize` as `u64`.
+///
+/// Some platforms do not support every request for every device. Those
+/// requests return `CustomBuiltinOnly` or `Unavailable` rather than an error.
 pub fn get_kernel_work_group_info<D: ClDeviceIdPtr>(obj: &Kernel, device_obj: D,
             request: KernelWorkGroupInfo) -> KernelWorkGroupInfoResult
 {
@@ -1997,8 +3282,11 @@ pub fn get_kernel_work_group_info<D: ClDeviceIdPtr>(obj: &Kernel, device_obj: D,
 //============================================================================
 
 /// Blocks until the first `num_events` events in `event_list` are complete.
+///
+/// Waiting on zero events is a no-op.
 pub fn wait_for_events(num_events: u32, event_list: &ClWaitListPtr) -> OclResult<()> {
     assert!(event_list.count() >= num_events);
+    if num_events == 0 { return Ok(()); }
 
     let errcode = unsafe {
         ffi::clWaitForEvents(num_events, event_list.as_ptr_ptr())
@@ -2044,6 +3332,11 @@ pub fn get_event_info<'e, E: ClEventPtrRef<'e>>(event: &'e E, request: EventInfo
 }
 
 /// Creates an event not already associated with any command.
+///
+/// The event starts out `CommandExecutionStatus::Submitted` and can be
+/// completed with `::set_user_event_status`.
+///
+/// [SDK Docs](https://www.khronos.org/registry/cl/sdk/1.2/docs/man/xhtml/clCreateUserEvent.html)
 pub fn create_user_event<C>(context: C) -> OclResult<Event>
         where C: ClContextPtr
 {
@@ -2052,7 +3345,8 @@ pub fn create_user_event<C>(context: C) -> OclResult<Event>
     eval_errcode(errcode, event, "clCreateUserEvent", "")
 }
 
-/// Increments an event's reference counter.
+/// Increments an event's reference counter. Must be balanced by a call to
+/// `release_event` (see `retain_context`).
 pub unsafe fn retain_event<'e, E: ClEventPtrRef<'e>>(event: &'e E) -> OclResult<()> {
     eval_errcode(ffi::clRetainEvent(*event.as_ptr_ref()), (), "clRetainEvent", "")
 }
@@ -2081,6 +3375,13 @@ pub unsafe fn release_event<'e, E: ClEventPtrRef<'e>>(event: &'e E) -> OclResult
 /// `::Complete`. Everything else is functionally equivalent and is useful
 /// only for debugging or profiling purposes (this may change in the future).
 ///
+/// Per the spec, the status may only be set once and should be either
+/// `::Complete` or a negative error code (which terminates any commands
+/// waiting on the event). Setting any other value, or setting the status a
+/// second time, is undefined behavior on some platforms.
+///
+/// [SDK Docs](https://www.khronos.org/registry/cl/sdk/1.2/docs/man/xhtml/clSetUserEventStatus.html)
+///
 pub fn set_user_event_status<'e,E: ClEventPtrRef<'e>>(event: &'e E,
             execution_status: CommandExecutionStatus) -> OclResult<()>
 {
@@ -2111,6 +3412,44 @@ pub unsafe fn set_event_callback<'e, E: ClEventPtrRef<'e>>(
     ), (), "clSetEventCallback", "")
 }
 
+/// Sets a Rust closure to be called as soon as the `callback_trigger` status
+/// is reached.
+///
+/// The closure is boxed and passed to OpenCL as `user_data` and is freed
+/// after it has been called. It receives the event (retained) along with its
+/// execution status, which will be negative if the command terminated
+/// abnormally.
+///
+/// If the event has already reached `callback_trigger`, the closure may be
+/// called immediately on the calling thread, before this function returns.
+/// Otherwise it is called from a thread owned by the OpenCL implementation
+/// and should return quickly, avoiding blocking OpenCL calls.
+///
+/// [SDK Docs](https://www.khronos.org/registry/cl/sdk/1.2/docs/man/xhtml/clSetEventCallback.html)
+pub fn set_event_callback_closure<'e, E, F>(
+            event: &'e E,
+            callback_trigger: CommandExecutionStatus,
+            callback: F,
+        ) -> OclResult<()>
+        where E: ClEventPtrRef<'e>, F: FnMut(Event, i32) + Send + 'static
+{
+    let user_data = Box::into_raw(Box::new(callback));
+
+    let errcode = unsafe { ffi::clSetEventCallback(
+        *event.as_ptr_ref(),
+        callback_trigger as cl_int,
+        Some(_event_callback_trampoline::<F>),
+        user_data as *mut c_void,
+    ) };
+
+    // The callback will never be called, reclaim and drop the box:
+    if errcode != Status::CL_SUCCESS as i32 {
+        unsafe { drop(Box::from_raw(user_data)); }
+    }
+
+    eval_errcode(errcode, (), "clSetEventCallback", "")
+}
+
 //============================================================================
 //============================ Profiling APIs ================================
 //============================================================================
@@ -2217,6 +3556,9 @@ pub unsafe fn enqueue_read_buffer<T, M, En, Ewl>(
         ) -> OclResult<()>
         where T: OclPrm, En: ClNullEventPtr, Ewl: ClWaitListPtr, M: AsMem<T> + MemCmdRw
 {
+    verify_mem_is_buffer(buffer.as_mem(), "::enqueue_read_buffer")?;
+    verify_mem_host_access(buffer.as_mem(), true, "::enqueue_read_buffer")?;
+
     let (wait_list_len, wait_list_ptr, new_event_ptr) =
         resolve_event_ptrs(wait_list, new_event);
 
@@ -2269,6 +3611,9 @@ pub unsafe fn enqueue_read_buffer_rect<T, M, En, Ewl>(
         ) -> OclResult<()>
         where T: OclPrm, En: ClNullEventPtr, Ewl: ClWaitListPtr, M: AsMem<T> + MemCmdRw
 {
+    verify_mem_is_buffer(buffer.as_mem(), "::enqueue_read_buffer_rect")?;
+    verify_mem_host_access(buffer.as_mem(), true, "::enqueue_read_buffer_rect")?;
+
     let buffer_origin_bytes = [buffer_origin[0] * mem::size_of::<T>(),
         buffer_origin[1], buffer_origin[2]];
     let host_origin_bytes = [host_origin[0] * mem::size_of::<T>(),
@@ -2327,6 +3672,9 @@ pub unsafe fn enqueue_write_buffer<T, M, En, Ewl>(
         ) -> OclResult<()>
         where T: OclPrm, En: ClNullEventPtr, Ewl: ClWaitListPtr, M: AsMem<T> + MemCmdRw
 {
+    verify_mem_is_buffer(buffer.as_mem(), "::enqueue_write_buffer")?;
+    verify_mem_host_access(buffer.as_mem(), false, "::enqueue_write_buffer")?;
+
     let (wait_list_len, wait_list_ptr, new_event_ptr) =
         resolve_event_ptrs(wait_list, new_event);
 
@@ -2343,6 +3691,7 @@ pub unsafe fn enqueue_write_buffer<T, M, En, Ewl>(
         wait_list_ptr,
         new_event_ptr,
     );
+    if errcode == 0 { bump_mem_generation(buffer.as_mem()); }
     eval_errcode(errcode, (), "clEnqueueWriteBuffer", "")
 }
 
@@ -2377,6 +3726,9 @@ pub unsafe fn enqueue_write_buffer_rect<T, M, En, Ewl>(
     ) -> OclResult<()>
     where T: OclPrm, En: ClNullEventPtr, Ewl: ClWaitListPtr, M: AsMem<T> + MemCmdRw
 {
+    verify_mem_is_buffer(buffer.as_mem(), "::enqueue_write_buffer_rect")?;
+    verify_mem_host_access(buffer.as_mem(), false, "::enqueue_write_buffer_rect")?;
+
     let (wait_list_len, wait_list_ptr, new_event_ptr) =
         resolve_event_ptrs(wait_list, new_event);
 
@@ -2402,6 +3754,7 @@ pub unsafe fn enqueue_write_buffer_rect<T, M, En, Ewl>(
         wait_list_ptr,
         new_event_ptr,
     );
+    if errcode == 0 { bump_mem_generation(buffer.as_mem()); }
     eval_errcode(errcode, (), "clEnqueueWriteBufferRect", "")
 }
 
@@ -2422,6 +3775,8 @@ pub fn enqueue_fill_buffer<T, M, En, Ewl>(
         ) -> OclResult<()>
         where T: OclPrm, En: ClNullEventPtr, Ewl: ClWaitListPtr, M: AsMem<T> + MemCmdRw
 {
+    verify_mem_is_buffer(buffer.as_mem(), "::enqueue_fill_buffer")?;
+
     verify_device_version(device_version, [1, 2], command_queue)
         .chain_err(|| "::enqueue_fill_buffer")?;
 
@@ -2443,6 +3798,7 @@ pub fn enqueue_fill_buffer<T, M, En, Ewl>(
         wait_list_ptr,
         new_event_ptr,
     ) };
+    if errcode == 0 { bump_mem_generation(buffer.as_mem()); }
     eval_errcode(errcode, (), "clEnqueueFillBuffer", "")
 }
 
@@ -2459,6 +3815,9 @@ pub fn enqueue_copy_buffer<T, M, En, Ewl>(
         ) -> OclResult<()>
         where T: OclPrm, En: ClNullEventPtr, Ewl: ClWaitListPtr, M: AsMem<T> + MemCmdAll
 {
+    verify_mem_is_buffer(src_buffer.as_mem(), "::enqueue_copy_buffer")?;
+    verify_mem_is_buffer(dst_buffer.as_mem(), "::enqueue_copy_buffer")?;
+
     let (wait_list_len, wait_list_ptr, new_event_ptr)
         = resolve_event_ptrs(wait_list, new_event);
 
@@ -2477,6 +3836,7 @@ pub fn enqueue_copy_buffer<T, M, En, Ewl>(
         wait_list_ptr,
         new_event_ptr,
     ) };
+    if errcode == 0 { bump_mem_generation(dst_buffer.as_mem()); }
     eval_errcode(errcode, (), "clEnqueueCopyBuffer", "")
 }
 
@@ -2501,6 +3861,9 @@ pub fn enqueue_copy_buffer_rect<T, M, En, Ewl>(
         ) -> OclResult<()>
         where T: OclPrm, En: ClNullEventPtr, Ewl: ClWaitListPtr, M: AsMem<T> + MemCmdAll
 {
+    verify_mem_is_buffer(src_buffer.as_mem(), "::enqueue_copy_buffer_rect")?;
+    verify_mem_is_buffer(dst_buffer.as_mem(), "::enqueue_copy_buffer_rect")?;
+
     let (wait_list_len, wait_list_ptr, new_event_ptr) =
         resolve_event_ptrs(wait_list, new_event);
 
@@ -2525,6 +3888,7 @@ pub fn enqueue_copy_buffer_rect<T, M, En, Ewl>(
         wait_list_ptr,
         new_event_ptr,
     ) };
+    if errcode == 0 { bump_mem_generation(dst_buffer.as_mem()); }
     eval_errcode(errcode, (), "clEnqueueCopyBufferRect", "")
 }
 
@@ -2550,12 +3914,18 @@ pub fn enqueue_acquire_gl_buffer<En, Ewl>(
         wait_list_ptr,
         new_event_ptr
     ) };
+    if errcode == 0 { bump_context_mem_generation(command_queue); }
     eval_errcode(errcode, (), "clEnqueueAcquireGLObjects", "")
 }
 
 /// Acquire OpenCL memory objects (buffers and images) that have been created
 /// from OpenGL objects.
 ///
+/// The context must have been created with OpenGL sharing enabled. Unless
+/// the `cl_khr_gl_event` extension is supported, all pending GL operations
+/// which access the objects must be completed (with `glFinish`) before
+/// calling this function.
+///
 /// To create a slice from a single `Mem` reference without any cost, use
 /// something like: `unsafe { ::std::slice::from_raw_parts(&core_mem, 1) };`.
 ///
@@ -2570,14 +3940,17 @@ pub fn enqueue_acquire_gl_objects<En, Ewl>(
     let (wait_list_len, wait_list_ptr, new_event_ptr) =
         resolve_event_ptrs(wait_list, new_event);
 
+    let buffer_ptrs: Vec<cl_mem> = buffers.iter().map(|b| b.as_ptr()).collect();
+
     let errcode = unsafe { clEnqueueAcquireGLObjects(
         command_queue.as_ptr(),
-        buffers.len() as u32,
-        buffers.as_ptr() as *const cl_mem,
+        buffer_ptrs.len() as u32,
+        buffer_ptrs.as_ptr(),
         wait_list_len,
         wait_list_ptr,
         new_event_ptr
     ) };
+    if errcode == 0 { bump_context_mem_generation(command_queue); }
     eval_errcode(errcode, (), "clEnqueueAcquireGLObjects", "")
 }
 
@@ -2610,6 +3983,10 @@ pub fn enqueue_release_gl_buffer<En, Ewl>(
 /// Release OpenCL memory objects (buffers and images) that have been created
 /// from OpenGL objects.
 ///
+/// Unless the `cl_khr_gl_event` extension is supported, all commands using
+/// the objects must be completed (with `::finish` or by waiting on
+/// `new_event`) before they are used by GL again.
+///
 /// To create a slice from a single `Mem` reference without any cost, use
 /// something like: `unsafe { ::std::slice::from_raw_parts(&core_mem, 1) };`.
 ///
@@ -2624,10 +4001,12 @@ pub fn enqueue_release_gl_objects<En, Ewl>(
     let (wait_list_len, wait_list_ptr, new_event_ptr) =
         resolve_event_ptrs(wait_list, new_event);
 
+    let buffer_ptrs: Vec<cl_mem> = buffers.iter().map(|b| b.as_ptr()).collect();
+
     let errcode = unsafe { clEnqueueReleaseGLObjects(
         command_queue.as_ptr(),
-        buffers.len() as u32,
-        buffers.as_ptr() as *const cl_mem,
+        buffer_ptrs.len() as u32,
+        buffer_ptrs.as_ptr(),
         wait_list_len,
         wait_list_ptr,
         new_event_ptr
@@ -2661,6 +4040,8 @@ pub unsafe fn enqueue_read_image<T, M, En, Ewl>(
         ) -> OclResult<()>
         where T: OclPrm, En: ClNullEventPtr, Ewl: ClWaitListPtr, M: AsMem<T> + MemCmdRw
 {
+    verify_mem_host_access(image.as_mem(), true, "::enqueue_read_image")?;
+
     let (wait_list_len, wait_list_ptr, new_event_ptr)
         = resolve_event_ptrs(wait_list, new_event);
 
@@ -2707,6 +4088,8 @@ pub unsafe fn enqueue_write_image<T, M, En, Ewl>(
         ) -> OclResult<()>
         where T: OclPrm, En: ClNullEventPtr, Ewl: ClWaitListPtr, M: AsMem<T> + MemCmdRw
 {
+    verify_mem_host_access(image.as_mem(), false, "::enqueue_write_image")?;
+
     let (wait_list_len, wait_list_ptr, new_event_ptr)
         = resolve_event_ptrs(wait_list, new_event);
 
@@ -2823,6 +4206,8 @@ pub fn enqueue_copy_image_to_buffer<T, M, En, Ewl>(
         ) -> OclResult<()>
         where T: OclPrm, En: ClNullEventPtr, Ewl: ClWaitListPtr, M: AsMem<T> + MemCmdAll
 {
+    verify_mem_is_buffer(dst_buffer.as_mem(), "::enqueue_copy_image_to_buffer")?;
+
     let dst_offset_bytes = dst_offset * mem::size_of::<T>();
 
     let (wait_list_len, wait_list_ptr, new_event_ptr)
@@ -2839,6 +4224,7 @@ pub fn enqueue_copy_image_to_buffer<T, M, En, Ewl>(
         wait_list_ptr,
         new_event_ptr,
     ) };
+    if errcode == 0 { bump_mem_generation(dst_buffer.as_mem()); }
     eval_errcode(errcode, (), "clEnqueueCopyImageToBuffer", "")
 }
 
@@ -2858,6 +4244,8 @@ pub fn enqueue_copy_buffer_to_image<T, M, En, Ewl>(
         ) -> OclResult<()>
         where T: OclPrm, En: ClNullEventPtr, Ewl: ClWaitListPtr, M: AsMem<T> + MemCmdAll
 {
+    verify_mem_is_buffer(src_buffer.as_mem(), "::enqueue_copy_buffer_to_image")?;
+
     let src_offset_bytes = src_offset * mem::size_of::<T>();
 
     let (wait_list_len, wait_list_ptr, new_event_ptr)
@@ -2941,6 +4329,9 @@ pub unsafe fn enqueue_map_buffer<T, M, En, Ewl>(
         ) -> OclResult<MemMap<T>>
         where T: OclPrm, En: ClNullEventPtr, Ewl: ClWaitListPtr, M: AsMem<T> + MemCmdAll
 {
+    verify_mem_is_buffer(buffer.as_mem(), "::enqueue_map_buffer")?;
+    map_flags.validate().chain_err(|| "::enqueue_map_buffer")?;
+
     let (wait_list_len, wait_list_ptr, new_event_ptr) =
         resolve_event_ptrs(wait_list, new_event);
 
@@ -2950,6 +4341,38 @@ pub unsafe fn enqueue_map_buffer<T, M, En, Ewl>(
     mapped_ptr_res.map(|ptr| MemMap::from_raw(ptr))
 }
 
+/// Enqueues a command to map a region of `buffer` into the host address
+/// space and returns a guard which unmaps the region when dropped.
+///
+/// The guard dereferences to a slice of the mapped region (see `MappedMem`).
+/// Dropping it enqueues an unmap command on `command_queue` and blocks until
+/// the unmap has completed.
+///
+/// [SDK Docs](https://www.khronos.org/registry/cl/sdk/1.2/docs/man/xhtml/clEnqueueMapBuffer.html)
+///
+/// ## Safety
+///
+/// If `block` is false, the caller must ensure that the guard is not
+/// dereferenced until the map is complete. Use `new_event` to monitor it.
+///
+pub unsafe fn enqueue_map_buffer_guarded<T, En, Ewl>(
+            command_queue: &CommandQueue,
+            buffer: &Mem,
+            block: bool,
+            map_flags: MapFlags,
+            offset: usize,
+            len: usize,
+            wait_list: Option<Ewl>,
+            new_event: Option<En>,
+        ) -> OclResult<MappedMem<T>>
+        where T: OclPrm, En: ClNullEventPtr, Ewl: ClWaitListPtr
+{
+    let map = enqueue_map_buffer::<T, _, _, _>(command_queue, buffer, block, map_flags, offset,
+        len, wait_list, new_event).chain_err(|| "::enqueue_map_buffer_guarded")?;
+
+    Ok(MappedMem::from_raw(map, len, map_flags, buffer.clone(), command_queue.clone()))
+}
+
 /// [UNTESTED]
 /// Enqueues a command to map a region of the image object given by `image` into
 /// the host address space and returns a pointer to this mapped region.
@@ -2986,6 +4409,7 @@ pub unsafe fn enqueue_map_image<T, M, En, Ewl>(
 {
     // let row_pitch_bytes = row_pitch * mem::size_of::<T>();
     // let slc_pitch_bytes = slc_pitch * mem::size_of::<T>();
+    map_flags.validate().chain_err(|| "::enqueue_map_image")?;
 
     let (wait_list_len, wait_list_ptr, new_event_ptr) =
         resolve_event_ptrs(wait_list, new_event);
@@ -3048,6 +4472,7 @@ pub fn enqueue_unmap_mem_object<T, M, En, Ewl>(
         new_event_ptr,
     ) };
 
+    if errcode == 0 { bump_mem_generation(memobj.as_mem()); }
     eval_errcode(errcode, (), "clEnqueueUnmapMemObject", "")
 }
 
@@ -3074,10 +4499,12 @@ pub fn enqueue_migrate_mem_objects<En: ClNullEventPtr, Ewl: ClWaitListPtr>(
     let (wait_list_len, wait_list_ptr, new_event_ptr)
         = resolve_event_ptrs(wait_list, new_event);
 
+    let mem_ptrs: Vec<cl_mem> = mem_objects.iter().map(|m| m.as_ptr()).collect();
+
     let errcode = unsafe { ffi::clEnqueueMigrateMemObjects(
         command_queue.as_ptr(),
-        mem_objects.len() as u32,
-        mem_objects.as_ptr() as *const _ as *const cl_mem,
+        mem_ptrs.len() as u32,
+        mem_ptrs.as_ptr(),
         flags.bits(),
         wait_list_len,
         wait_list_ptr,
@@ -3086,6 +4513,271 @@ pub fn enqueue_migrate_mem_objects<En: ClNullEventPtr, Ewl: ClWaitListPtr>(
     eval_errcode(errcode, (), "clEnqueueMigrateMemObjects", "")
 }
 
+/// Enqueues a command to copy `size` bytes from `src_ptr` to `dst_ptr`, either
+/// of which may be a shared virtual memory (SVM) pointer or host memory.
+///
+/// `clEnqueueSVMMemcpy` forbids overlapping source and destination regions.
+/// Because the extent of an SVM allocation cannot be determined from a raw
+/// pointer, only the ranges described by `size` are checked for overlap and
+/// an error is returned without enqueuing anything if they overlap.
+///
+/// ## Safety
+///
+/// Both pointers must be valid for `size` bytes until the copy is complete.
+///
+/// [SDK Docs](https://www.khronos.org/registry/cl/sdk/2.0/docs/man/xhtml/clEnqueueSVMMemcpy.html)
+///
+/// [Version Controlled: OpenCL 2.0+] See module docs for more info.
+#[cfg(feature = "opencl_version_2_0")]
+pub unsafe fn enqueue_svm_memcpy<En: ClNullEventPtr, Ewl: ClWaitListPtr>(
+            command_queue: &CommandQueue,
+            block: bool,
+            dst_ptr: *mut c_void,
+            src_ptr: *const c_void,
+            size: usize,
+            wait_list: Option<Ewl>,
+            new_event: Option<En>,
+            device_version: Option<&OpenclVersion>
+        ) -> OclResult<()>
+{
+    let (dst_start, src_start) = (dst_ptr as usize, src_ptr as usize);
+
+    if !dst_ptr.is_null() && !src_ptr.is_null() && size > 0 &&
+            dst_start < src_start.saturating_add(size) &&
+            src_start < dst_start.saturating_add(size) {
+        return OclError::err_string(format!("::enqueue_svm_memcpy: The source \
+            ({:?}..+{}) and destination ({:?}..+{}) ranges overlap.", src_ptr, size, dst_ptr,
+            size));
+    }
+
+    verify_device_version(device_version, [2, 0], command_queue)
+        .chain_err(|| "::enqueue_svm_memcpy")?;
+
+    let (wait_list_len, wait_list_ptr, new_event_ptr) =
+        resolve_event_ptrs(wait_list, new_event);
+
+    let errcode = ffi::clEnqueueSVMMemcpy(
+        command_queue.as_ptr(),
+        block as cl_uint,
+        dst_ptr,
+        src_ptr,
+        size,
+        wait_list_len,
+        wait_list_ptr,
+        new_event_ptr,
+    );
+
+    eval_errcode(errcode, (), "clEnqueueSVMMemcpy", "")
+}
+
+/// Enqueues a command to map `size` bytes of the coarse-grained shared
+/// virtual memory (SVM) region at `svm_ptr` for access by the host.
+///
+/// Once the command completes, the region may be accessed through `svm_ptr`
+/// until it is unmapped with `::enqueue_svm_unmap`. Fine-grained regions
+/// need not be mapped.
+///
+/// ## Safety
+///
+/// `svm_ptr` must point into a region allocated with `clSVMAlloc` which is
+/// valid for `size` bytes.
+///
+/// [SDK Docs](https://www.khronos.org/registry/cl/sdk/2.0/docs/man/xhtml/clEnqueueSVMMap.html)
+///
+/// [Version Controlled: OpenCL 2.0+] See module docs for more info.
+#[cfg(feature = "opencl_version_2_0")]
+pub unsafe fn enqueue_svm_map<En: ClNullEventPtr, Ewl: ClWaitListPtr>(
+            command_queue: &CommandQueue,
+            block: bool,
+            map_flags: MapFlags,
+            svm_ptr: *mut c_void,
+            size: usize,
+            wait_list: Option<Ewl>,
+            new_event: Option<En>,
+            device_version: Option<&OpenclVersion>
+        ) -> OclResult<()>
+{
+    map_flags.validate().chain_err(|| "::enqueue_svm_map")?;
+    verify_device_version(device_version, [2, 0], command_queue)
+        .chain_err(|| "::enqueue_svm_map")?;
+
+    let (wait_list_len, wait_list_ptr, new_event_ptr) =
+        resolve_event_ptrs(wait_list, new_event);
+
+    let errcode = ffi::clEnqueueSVMMap(
+        command_queue.as_ptr(),
+        block as cl_uint,
+        map_flags.bits(),
+        svm_ptr,
+        size,
+        wait_list_len,
+        wait_list_ptr,
+        new_event_ptr,
+    );
+
+    eval_errcode(errcode, (), "clEnqueueSVMMap", "")
+}
+
+/// Enqueues a command to unmap a shared virtual memory (SVM) region
+/// previously mapped with `::enqueue_svm_map`.
+///
+/// ## Safety
+///
+/// The region at `svm_ptr` must not be accessed by the host after this
+/// command has been enqueued.
+///
+/// [SDK Docs](https://www.khronos.org/registry/cl/sdk/2.0/docs/man/xhtml/clEnqueueSVMUnmap.html)
+///
+/// [Version Controlled: OpenCL 2.0+] See module docs for more info.
+#[cfg(feature = "opencl_version_2_0")]
+pub unsafe fn enqueue_svm_unmap<En: ClNullEventPtr, Ewl: ClWaitListPtr>(
+            command_queue: &CommandQueue,
+            svm_ptr: *mut c_void,
+            wait_list: Option<Ewl>,
+            new_event: Option<En>,
+            device_version: Option<&OpenclVersion>
+        ) -> OclResult<()>
+{
+    verify_device_version(device_version, [2, 0], command_queue)
+        .chain_err(|| "::enqueue_svm_unmap")?;
+
+    let (wait_list_len, wait_list_ptr, new_event_ptr) =
+        resolve_event_ptrs(wait_list, new_event);
+
+    let errcode = ffi::clEnqueueSVMUnmap(
+        command_queue.as_ptr(),
+        svm_ptr,
+        wait_list_len,
+        wait_list_ptr,
+        new_event_ptr,
+    );
+
+    eval_errcode(errcode, (), "clEnqueueSVMUnmap", "")
+}
+
+/// Enqueues a command to fill `size` bytes of the shared virtual memory (SVM)
+/// region at `svm_ptr` with repeated copies of `pattern`.
+///
+/// The length of `pattern` must be a power of two no greater than 128 bytes
+/// (the size of the largest OpenCL built-in type) and must evenly divide
+/// `size`. `pattern` is copied before this function returns.
+///
+/// ## Safety
+///
+/// `svm_ptr` must point into a region allocated with `clSVMAlloc` which is
+/// valid for `size` bytes and aligned to the length of `pattern`.
+///
+/// [SDK Docs](https://www.khronos.org/registry/cl/sdk/2.0/docs/man/xhtml/clEnqueueSVMMemFill.html)
+///
+/// [Version Controlled: OpenCL 2.0+] See module docs for more info.
+#[cfg(feature = "opencl_version_2_0")]
+pub unsafe fn enqueue_svm_mem_fill<En: ClNullEventPtr, Ewl: ClWaitListPtr>(
+            command_queue: &CommandQueue,
+            svm_ptr: *mut c_void,
+            pattern: &[u8],
+            size: usize,
+            wait_list: Option<Ewl>,
+            new_event: Option<En>,
+            device_version: Option<&OpenclVersion>
+        ) -> OclResult<()>
+{
+    let pattern_size = pattern.len();
+
+    if !pattern_size.is_power_of_two() || pattern_size > 128 {
+        return OclError::err_string(format!("::enqueue_svm_mem_fill: The pattern length ({}) \
+            must be a power of two no greater than 128.", pattern_size));
+    }
+
+    if size % pattern_size != 0 {
+        return OclError::err_string(format!("::enqueue_svm_mem_fill: The fill size ({}) must \
+            be a multiple of the pattern length ({}).", size, pattern_size));
+    }
+
+    verify_device_version(device_version, [2, 0], command_queue)
+        .chain_err(|| "::enqueue_svm_mem_fill")?;
+
+    let (wait_list_len, wait_list_ptr, new_event_ptr) =
+        resolve_event_ptrs(wait_list, new_event);
+
+    let errcode = ffi::clEnqueueSVMMemFill(
+        command_queue.as_ptr(),
+        svm_ptr,
+        pattern.as_ptr() as *const c_void,
+        pattern_size,
+        size,
+        wait_list_len,
+        wait_list_ptr,
+        new_event_ptr,
+    );
+
+    eval_errcode(errcode, (), "clEnqueueSVMMemFill", "")
+}
+
+/// Enqueues a command to free the shared virtual memory (SVM) regions in
+/// `svm_ptrs` once all previously enqueued commands which use them (as
+/// determined by queue order and `wait_list`) have completed.
+///
+/// If `callback` is specified, it is called after the regions have been
+/// freed, from a thread owned by the OpenCL implementation.
+///
+/// ## Safety
+///
+/// Each pointer must have been returned by `clSVMAlloc` for the context
+/// associated with `command_queue` and must not be used after this command
+/// has been enqueued.
+///
+/// [SDK Docs](https://www.khronos.org/registry/cl/sdk/2.0/docs/man/xhtml/clEnqueueSVMFree.html)
+///
+/// [Version Controlled: OpenCL 2.0+] See module docs for more info.
+#[cfg(feature = "opencl_version_2_0")]
+pub unsafe fn enqueue_svm_free<F, En, Ewl>(
+            command_queue: &CommandQueue,
+            svm_ptrs: &[*mut c_void],
+            callback: Option<F>,
+            wait_list: Option<Ewl>,
+            new_event: Option<En>,
+            device_version: Option<&OpenclVersion>
+        ) -> OclResult<()>
+        where F: FnOnce() + Send + 'static, En: ClNullEventPtr, Ewl: ClWaitListPtr
+{
+    if svm_ptrs.is_empty() {
+        return OclError::err_string("::enqueue_svm_free: No SVM pointers specified.");
+    }
+
+    verify_device_version(device_version, [2, 0], command_queue)
+        .chain_err(|| "::enqueue_svm_free")?;
+
+    let (pfn_free_func, user_data) = match callback {
+        Some(cb) => {
+            let context = command_queue.context()?;
+            let user_data = Box::into_raw(Box::new((context, cb)));
+            (Some(_svm_free_trampoline::<F> as extern "C" fn(_, _, _, _)),
+                user_data as *mut c_void)
+        },
+        None => (None, ptr::null_mut()),
+    };
+
+    let (wait_list_len, wait_list_ptr, new_event_ptr) =
+        resolve_event_ptrs(wait_list, new_event);
+
+    let errcode = ffi::clEnqueueSVMFree(
+        command_queue.as_ptr(),
+        svm_ptrs.len() as cl_uint,
+        svm_ptrs.as_ptr() as *const *const c_void,
+        pfn_free_func,
+        user_data,
+        wait_list_len,
+        wait_list_ptr,
+        new_event_ptr,
+    );
+
+    if errcode != Status::CL_SUCCESS as i32 && !user_data.is_null() {
+        drop(Box::from_raw(user_data as *mut (Context, F)));
+    }
+
+    eval_errcode(errcode, (), "clEnqueueSVMFree", "")
+}
+
 /// Enqueues a command to execute a kernel on a device.
 ///
 /// # Safety
@@ -3180,58 +4872,284 @@ pub unsafe fn enqueue_kernel<En: ClNullEventPtr, Ewl: ClWaitListPtr> (
         let name = get_kernel_name(kernel);
         eval_errcode(errcode, (), "clEnqueueNDRangeKernel", &name)
     } else {
+        bump_context_mem_generation(command_queue);
         Ok(())
     }
 }
 
-/// [UNTESTED] Enqueues a command to execute a kernel on a device.
-///
-/// The kernel is executed using a single work-item.
-///
-/// From [SDK]: clEnqueueTask is equivalent to calling clEnqueueNDRangeKernel
-/// with work_dim = 1, global_work_offset = NULL, global_work_size[0] set to 1,
-/// and local_work_size[0] set to 1.
+/// Enqueues a command to execute a kernel on a device using slices for the
+/// global work offset, global work size, and local work size.
 ///
-/// [SDK]: https://www.khronos.org/registry/cl/sdk/1.0/docs/man/xhtml/clEnqueueTask.html
+/// The number of work dimensions is taken from `global_work_size`, which
+/// must contain between one and three elements. `global_work_offset` and
+/// `local_work_size`, when specified, must contain the same number of
+/// elements. Mismatched or out of range dimension counts are reported before
+/// the kernel is enqueued.
 ///
-pub unsafe fn enqueue_task<En: ClNullEventPtr, Ewl: ClWaitListPtr>(
+/// See `::enqueue_kernel` for details.
+pub unsafe fn enqueue_kernel_ndrange<En: ClNullEventPtr, Ewl: ClWaitListPtr> (
             command_queue: &CommandQueue,
             kernel: &Kernel,
+            global_work_offset: Option<&[usize]>,
+            global_work_size: &[usize],
+            local_work_size: Option<&[usize]>,
             wait_list: Option<Ewl>,
             new_event: Option<En>,
-            kernel_name: Option<&str>
         ) -> OclResult<()>
 {
-    let (wait_list_len, wait_list_ptr, new_event_ptr) =
-        resolve_event_ptrs(wait_list, new_event);
+    let work_dims = global_work_size.len();
 
-    let errcode = ffi::clEnqueueTask(
-        command_queue.as_ptr(),
-        kernel.as_ptr() as cl_kernel,
-        wait_list_len,
-        wait_list_ptr,
-        new_event_ptr,
-    );
-    eval_errcode(errcode, (), "clEnqueueTask", kernel_name.unwrap_or(""))
-}
+    if work_dims == 0 || work_dims > 3 {
+        return Err(OclError::unspecified_dimensions()).chain_err(|| format!(
+            "::enqueue_kernel_ndrange: The global work size must have between 1 and 3 \
+            dimensions (found: {}).", work_dims));
+    }
 
-/// [UNIMPLEMENTED]
-pub fn enqueue_native_kernel() -> OclResult<()> {
-    // ffi::clEnqueueNativeKernel(command_queue: cl_command_queue,
-    //                          user_func: extern fn (*mut c_void),
-    //                          args: *mut c_void,
-    //                          cb_args: size_t,
-    //                          num_mem_objects: cl_uint,
-    //                          mem_list: *const cl_mem,
-    //                          args_mem_loc: *const *const c_void,
-    //                          num_events_in_wait_list: cl_uint,
-    //                          event_wait_list: *const cl_event,
-    //                          event: *mut cl_event) -> cl_int;
-    unimplemented!();
-}
+    fn to_array(dims: &[usize], name: &str, work_dims: usize) -> OclResult<[usize; 3]> {
+        if dims.len() != work_dims {
+            return OclError::err_string(format!("::enqueue_kernel_ndrange: The number of {} \
+                dimensions ({}) does not match the number of global work size dimensions ({}).",
+                name, dims.len(), work_dims));
+        }
+        let mut array = [0; 3];
+        array[..work_dims].copy_from_slice(dims);
+        Ok(array)
+    }
 
-/// Enqueues a marker command which waits for either a list of events to
-/// complete, or all previously enqueued commands to complete.
+    let gwo = match global_work_offset {
+        Some(o) => Some(to_array(o, "global work offset", work_dims)?),
+        None => None,
+    };
+    let lws = match local_work_size {
+        Some(l) => Some(to_array(l, "local work size", work_dims)?),
+        None => None,
+    };
+    // Unused dimensions are ignored by the driver but padded to `1` for clarity:
+    let mut gws = [1; 3];
+    gws[..work_dims].copy_from_slice(global_work_size);
+
+    enqueue_kernel(command_queue, kernel, work_dims as u32, gwo, &gws, lws, wait_list, new_event)
+}
+
+/// Returns a local work size which evenly divides `global`, fits within
+/// `max_group_size` and `max_item_sizes`, and has a first dimension which is
+/// a multiple of `multiple`. Returns `None` if no such size exists.
+fn auto_local_work_size(global: &[usize], max_group_size: usize, multiple: usize,
+        max_item_sizes: &[usize]) -> Option<Vec<usize>>
+{
+    let largest_divisor = |g: usize, limit: usize, multiple: usize| {
+        (1..(cmp::min(g, limit) + 1)).rev().find(|&d| g % d == 0 && d % multiple == 0)
+    };
+
+    let mut remaining = max_group_size;
+    let mut local = Vec::with_capacity(global.len());
+
+    for (idx, &g) in global.iter().enumerate() {
+        let limit = cmp::min(remaining, max_item_sizes.get(idx).cloned().unwrap_or(1));
+        // Only the first dimension need be a multiple of the preferred size:
+        let dim_multiple = if idx == 0 { cmp::max(multiple, 1) } else { 1 };
+        let d = match largest_divisor(g, limit, dim_multiple) {
+            Some(d) => d,
+            None => return None,
+        };
+        remaining /= d;
+        local.push(d);
+    }
+
+    Some(local)
+}
+
+/// Enqueues a command to execute a kernel on a device, choosing the local
+/// work size automatically.
+///
+/// The local work size is derived from the kernel's `WorkGroupSize` and
+/// `PreferredWorkGroupSizeMultiple` for the device associated with
+/// `command_queue`, and from the device's `MaxWorkItemSizes`. It always
+/// divides `global_work_size` evenly. When no such size exists (e.g. a
+/// prime global size) no local work size is passed and the driver chooses
+/// one. Non-uniform work-groups are never used because their availability
+/// depends on the options the program was built with rather than on the
+/// device version alone.
+pub unsafe fn enqueue_kernel_auto<En: ClNullEventPtr, Ewl: ClWaitListPtr> (
+            command_queue: &CommandQueue,
+            kernel: &Kernel,
+            global_work_size: &[usize],
+            wait_list: Option<Ewl>,
+            new_event: Option<En>,
+        ) -> OclResult<()>
+{
+    let device = command_queue.device().chain_err(|| "::enqueue_kernel_auto")?;
+    validate_work_dims(&device, global_work_size, None).chain_err(|| "::enqueue_kernel_auto")?;
+
+    let max_group_size = match get_kernel_work_group_info(kernel, &device,
+            KernelWorkGroupInfo::WorkGroupSize) {
+        KernelWorkGroupInfoResult::WorkGroupSize(s) => Some(s),
+        KernelWorkGroupInfoResult::Error(err) => return Err(*err),
+        _ => None,
+    };
+    let multiple = match get_kernel_work_group_info(kernel, &device,
+            KernelWorkGroupInfo::PreferredWorkGroupSizeMultiple) {
+        KernelWorkGroupInfoResult::PreferredWorkGroupSizeMultiple(s) => Some(s),
+        KernelWorkGroupInfoResult::Error(err) => return Err(*err),
+        _ => None,
+    };
+    let max_item_sizes = get_device_max_work_item_sizes(&device)
+        .chain_err(|| "::enqueue_kernel_auto")?;
+
+    // Let the driver choose if the kernel's limits are unavailable:
+    let local_work_size = match (max_group_size, multiple) {
+        (Some(max_group_size), Some(multiple)) => auto_local_work_size(global_work_size,
+            max_group_size, multiple, &max_item_sizes),
+        _ => None,
+    };
+
+    enqueue_kernel_ndrange(command_queue, kernel, None, global_work_size,
+        local_work_size.as_ref().map(|l| &l[..]), wait_list, new_event)
+}
+
+/// [UNTESTED] Enqueues a command to execute a kernel on a device.
+///
+/// The kernel is executed using a single work-item.
+///
+/// From [SDK]: clEnqueueTask is equivalent to calling clEnqueueNDRangeKernel
+/// with work_dim = 1, global_work_offset = NULL, global_work_size[0] set to 1,
+/// and local_work_size[0] set to 1.
+///
+/// `clEnqueueTask` is deprecated as of OpenCL 2.0. When the device associated
+/// with `command_queue` reports version 2.0 or higher, the kernel is instead
+/// enqueued using `::enqueue_kernel` with a global work size of `[1]` and no
+/// local work size.
+///
+/// [SDK]: https://www.khronos.org/registry/cl/sdk/1.0/docs/man/xhtml/clEnqueueTask.html
+///
+pub unsafe fn enqueue_task<En: ClNullEventPtr, Ewl: ClWaitListPtr>(
+            command_queue: &CommandQueue,
+            kernel: &Kernel,
+            wait_list: Option<Ewl>,
+            new_event: Option<En>,
+            kernel_name: Option<&str>
+        ) -> OclResult<()>
+{
+    let device_versions = command_queue.device_versions().chain_err(|| "::enqueue_task")?;
+
+    if device_versions.iter().any(|v| *v >= OpenclVersion::new(2, 0)) {
+        return enqueue_kernel(command_queue, kernel, 1, None, &[1, 1, 1], None, wait_list,
+            new_event);
+    }
+
+    let (wait_list_len, wait_list_ptr, new_event_ptr) =
+        resolve_event_ptrs(wait_list, new_event);
+
+    let errcode = ffi::clEnqueueTask(
+        command_queue.as_ptr(),
+        kernel.as_ptr() as cl_kernel,
+        wait_list_len,
+        wait_list_ptr,
+        new_event_ptr,
+    );
+    if errcode != 0 {
+        let name = kernel_name.map(String::from).unwrap_or_else(|| get_kernel_name(kernel));
+        eval_errcode(errcode, (), "clEnqueueTask", &name)
+    } else {
+        bump_context_mem_generation(command_queue);
+        Ok(())
+    }
+}
+
+/// Enqueues a host function (`func`) to be executed as a native kernel.
+///
+/// Each memory object in `mem_objects` is made available to `func` as a
+/// pointer to its global memory, in the same order, via the slice passed to
+/// `func`. Event ordering (`wait_list` and `new_event`) behaves exactly as
+/// with any other command.
+///
+/// Unlike `clEnqueueNativeKernel`, no `mem_locs` list is accepted: those
+/// locations point into the argument block passed to the implementation,
+/// which is built (and owned) by this function in order to carry `func`.
+/// They are therefore computed here, one per entry in `mem_objects`.
+///
+/// The device associated with `command_queue` must report
+/// `EXEC_NATIVE_KERNEL` among its execution capabilities
+/// (`DeviceInfo::ExecutionCapabilities`). An error is returned otherwise.
+///
+/// ### Safety
+///
+/// `func` is called from an implementation-owned thread and receives raw
+/// pointers into device global memory. It must not outlive the memory
+/// objects it accesses nor call OpenCL API functions.
+///
+/// [SDK Docs](https://www.khronos.org/registry/cl/sdk/1.2/docs/man/xhtml/clEnqueueNativeKernel.html)
+pub unsafe fn enqueue_native_kernel<F, En, Ewl>(
+            command_queue: &CommandQueue,
+            func: F,
+            mem_objects: &[Mem],
+            wait_list: Option<Ewl>,
+            new_event: Option<En>,
+        ) -> OclResult<()>
+        where F: FnOnce(&[*mut c_void]) + Send + 'static, En: ClNullEventPtr, Ewl: ClWaitListPtr
+{
+    let device = match get_command_queue_info(command_queue, CommandQueueInfo::Device) {
+        CommandQueueInfoResult::Device(d) => d,
+        CommandQueueInfoResult::Error(err) => return Err(*err),
+        _ => unreachable!(),
+    };
+
+    let caps = device_exec_capabilities(&device).chain_err(|| "::enqueue_native_kernel")?;
+
+    if !caps.contains(::EXEC_NATIVE_KERNEL) {
+        return OclError::err_string(format!("::enqueue_native_kernel: The device \
+            associated with this command queue ({:?}) does not support native kernels \
+            (execution capabilities: {:?}).", device, caps));
+    }
+
+    // Argument block: `[closure_ptr, mem_count, mem_ptr_0, ...]`. The
+    // implementation copies the block and replaces each `mem_ptr` (located
+    // via `mem_locs`) with a pointer to global memory:
+    let callback_ptr = Box::into_raw(Box::new(func)) as *mut c_void;
+    let mut args: Vec<*mut c_void> = Vec::with_capacity(mem_objects.len() + 2);
+    args.push(callback_ptr);
+    args.push(mem_objects.len() as *mut c_void);
+    args.extend(iter::repeat(ptr::null_mut()).take(mem_objects.len()));
+
+    let mem_locs: Vec<*const c_void> = (0..mem_objects.len())
+        .map(|i| args.as_ptr().offset(i as isize + 2) as *const c_void)
+        .collect();
+
+    let mem_ptrs: Vec<cl_mem> = mem_objects.iter().map(|m| m.as_ptr()).collect();
+
+    let (mem_list_ptr, mem_locs_ptr) = if mem_objects.is_empty() {
+        (ptr::null(), ptr::null())
+    } else {
+        (mem_ptrs.as_ptr(), mem_locs.as_ptr())
+    };
+
+    let (wait_list_len, wait_list_ptr, new_event_ptr) =
+        resolve_event_ptrs(wait_list, new_event);
+
+    let errcode = ffi::clEnqueueNativeKernel(
+        command_queue.as_ptr(),
+        Some(_native_kernel_trampoline::<F>),
+        args.as_mut_ptr() as *mut c_void,
+        args.len() * mem::size_of::<*mut c_void>(),
+        mem_objects.len() as cl_uint,
+        mem_list_ptr,
+        mem_locs_ptr,
+        wait_list_len,
+        wait_list_ptr,
+        new_event_ptr,
+    );
+
+    // The closure will never be called if the command was not enqueued:
+    if errcode != Status::CL_SUCCESS as i32 {
+        let _ = Box::from_raw(callback_ptr as *mut F);
+    }
+
+    if errcode == 0 { bump_context_mem_generation(command_queue); }
+    eval_errcode(errcode, (), "clEnqueueNativeKernel", "")
+}
+
+/// Enqueues a marker command which waits for either a list of events to
+/// complete, or all previously enqueued commands to complete.
 ///
 /// [SDK Docs](https://www.khronos.org/registry/cl/sdk/1.2/docs/man/xhtml/clEnqueueMarkerWithWaitList.html)
 ///
@@ -3260,6 +5178,25 @@ pub fn enqueue_marker_with_wait_list<En, Ewl>(
     eval_errcode(errcode, (), "clEnqueueMarkerWithWaitList", "")
 }
 
+/// Enqueues a marker command which completes once all previously enqueued
+/// commands have completed.
+///
+/// This is the OpenCL 1.1 predecessor of `::enqueue_marker_with_wait_list`
+/// (deprecated as of 1.2) and is intended as a fallback for devices which
+/// do not support the newer command. Unlike the newer version, `new_event`
+/// is required.
+///
+/// [SDK Docs](https://www.khronos.org/registry/cl/sdk/1.1/docs/man/xhtml/clEnqueueMarker.html)
+pub fn enqueue_marker<En>(command_queue: &CommandQueue, mut new_event: En) -> OclResult<()>
+        where En: ClNullEventPtr
+{
+    let errcode = unsafe { ffi::clEnqueueMarker(
+        command_queue.as_ptr(),
+        new_event.alloc_new(),
+    ) };
+    eval_errcode(errcode, (), "clEnqueueMarker", "")
+}
+
 /// A synchronization point that enqueues a barrier operation.
 ///
 /// [SDK Docs](https://www.khronos.org/registry/cl/sdk/1.2/docs/man/xhtml/clEnqueueBarrierWithWaitList.html)
@@ -3356,8 +5293,9 @@ pub unsafe fn get_extension_function_address_for_platform(
     );
 
     if ext_fn == 0 as *mut c_void {
-        OclError::err_string("The specified function does not exist for the implementation or 'platform' \
-            is not a valid platform.")
+        OclError::err_string(format!("::get_extension_function_address_for_platform: The \
+            specified function ('{}') does not exist for the implementation or 'platform' is \
+            not a valid platform.", func_name))
     } else {
         Ok(ext_fn)
     }
@@ -3381,6 +5319,260 @@ pub fn device_versions(device_ids: &[DeviceId]) -> OclResult<Vec<OpenclVersion>>
     Ok(d_versions)
 }
 
+/// Creates a context containing only `device`, using the platform the device
+/// belongs to.
+pub fn create_context_single<D: ClDeviceIdPtr>(device: D) -> OclResult<Context> {
+    let platform = match get_device_info(device, DeviceInfo::Platform) {
+        DeviceInfoResult::Platform(p) => p,
+        DeviceInfoResult::Error(err) => return Err(*err),
+        _ => unreachable!(),
+    };
+
+    let properties = ContextProperties::new().platform(platform);
+    create_context(Some(&properties), &[device], None, None)
+}
+
+/// Returns the profile (full or embedded) supported by a device.
+pub fn device_profile<D: ClDeviceIdPtr>(device: D) -> OclResult<Profile> {
+    match get_device_info(device, DeviceInfo::Profile) {
+        DeviceInfoResult::Profile(p) => Profile::from_info_str(&p),
+        DeviceInfoResult::Error(err) => Err(*err),
+        _ => unreachable!(),
+    }
+}
+
+/// Splits a space-separated extensions string, as reported by
+/// `PlatformInfo::Extensions` or `DeviceInfo::Extensions`, into a set of
+/// extension names.
+pub fn split_extensions(extensions: &str) -> HashSet<String> {
+    extensions.split_whitespace().map(|ext| ext.to_owned()).collect()
+}
+
+/// Returns the set of extensions supported by `device`.
+pub fn get_device_extensions<D: ClDeviceIdPtr>(device: D) -> OclResult<HashSet<String>> {
+    match get_device_info(device, DeviceInfo::Extensions) {
+        DeviceInfoResult::Extensions(exts) => Ok(split_extensions(&exts)),
+        DeviceInfoResult::Error(err) => Err(*err),
+        _ => unreachable!(),
+    }
+}
+
+/// Returns the set of extensions supported by every device on `platform`.
+pub fn get_platform_extensions<P: ClPlatformIdPtr>(platform: P) -> OclResult<HashSet<String>> {
+    match get_platform_info(platform, PlatformInfo::Extensions) {
+        PlatformInfoResult::Extensions(exts) => Ok(split_extensions(&exts)),
+        PlatformInfoResult::Error(err) => Err(*err),
+        _ => unreachable!(),
+    }
+}
+
+/// Returns true if `device` supports the extension named exactly `name`.
+///
+/// Unlike searching the raw extensions string, `"cl_khr_fp16"` will not
+/// match an extension such as `"cl_khr_fp16_foo"`.
+pub fn device_has_extension<D: ClDeviceIdPtr>(device: D, name: &str) -> OclResult<bool> {
+    match get_device_info(device, DeviceInfo::Extensions) {
+        DeviceInfoResult::Extensions(exts) => Ok(exts.split_whitespace().any(|ext| ext == name)),
+        DeviceInfoResult::Error(err) => Err(*err),
+        _ => unreachable!(),
+    }
+}
+
+/// Returns true if a device supports 64-bit integer types (`long` and
+/// `ulong`) within kernels.
+///
+/// 64-bit integers are mandatory for full profile devices but optional for
+/// embedded profile devices, which must report the `cles_khr_int64`
+/// extension.
+pub fn device_supports_int64<D: ClDeviceIdPtr>(device: D) -> OclResult<bool> {
+    if !device_profile(device)?.is_embedded() {
+        return Ok(true);
+    }
+
+    device_has_extension(device, "cles_khr_int64")
+}
+
+/// Returns true if a device supports half-precision floating point types
+/// within kernels (the `cl_khr_fp16` extension).
+///
+/// Kernels using `half` must still enable the extension with
+/// `#pragma OPENCL EXTENSION cl_khr_fp16 : enable`.
+pub fn device_has_fp16<D: ClDeviceIdPtr>(device: D) -> OclResult<bool> {
+    device_has_extension(device, "cl_khr_fp16")
+}
+
+/// Verifies that a kernel launch configuration fits within the limits of
+/// `device`, returning an error naming the violated limit otherwise.
+///
+/// * The number of dimensions of `global` (and `local`, which must match)
+///   may not exceed `DeviceInfo::MaxWorkItemDimensions`.
+/// * Each dimension of `global` must be non-zero.
+/// * Each dimension of `local` may not exceed the corresponding value of
+///   `DeviceInfo::MaxWorkItemSizes`.
+/// * The product of `local` may not exceed `DeviceInfo::MaxWorkGroupSize`.
+///
+/// Global sizes are not limited by the work-item sizes, which apply only to
+/// a single work-group.
+pub fn validate_work_dims<D: ClDeviceIdPtr>(device: D, global: &[usize], local: Option<&[usize]>)
+        -> OclResult<()>
+{
+    let max_dims = match get_device_info(device, DeviceInfo::MaxWorkItemDimensions) {
+        DeviceInfoResult::MaxWorkItemDimensions(d) => d as usize,
+        DeviceInfoResult::Error(err) => return Err(*err),
+        _ => unreachable!(),
+    };
+
+    if global.is_empty() || global.len() > max_dims {
+        return OclError::err_string(format!("::validate_work_dims: The number of global work \
+            dimensions ({}) must be between 1 and 'MaxWorkItemDimensions' ({}).",
+            global.len(), max_dims));
+    }
+
+    if let Some(idx) = global.iter().position(|&g| g == 0) {
+        return OclError::err_string(format!("::validate_work_dims: Global work size \
+            dimension [{}] is zero.", idx));
+    }
+
+    let local = match local {
+        Some(l) => l,
+        None => return Ok(()),
+    };
+
+    if local.len() != global.len() {
+        return OclError::err_string(format!("::validate_work_dims: The number of local work \
+            dimensions ({}) does not match the number of global work dimensions ({}).",
+            local.len(), global.len()));
+    }
+
+    let max_sizes = match get_device_info(device, DeviceInfo::MaxWorkItemSizes) {
+        DeviceInfoResult::MaxWorkItemSizes(s) => s,
+        DeviceInfoResult::Error(err) => return Err(*err),
+        _ => unreachable!(),
+    };
+
+    for (idx, (&l, &max)) in local.iter().zip(max_sizes.iter()).enumerate() {
+        if l == 0 || l > max {
+            return OclError::err_string(format!("::validate_work_dims: Local work size \
+                dimension [{}] ({}) must be between 1 and 'MaxWorkItemSizes[{}]' ({}).",
+                idx, l, idx, max));
+        }
+    }
+
+    let max_group_size = match get_device_info(device, DeviceInfo::MaxWorkGroupSize) {
+        DeviceInfoResult::MaxWorkGroupSize(s) => s,
+        DeviceInfoResult::Error(err) => return Err(*err),
+        _ => unreachable!(),
+    };
+
+    let group_size = local.iter().fold(1usize, |acc, &l| acc.saturating_mul(l));
+
+    if group_size > max_group_size {
+        return OclError::err_string(format!("::validate_work_dims: The local work-group size \
+            ({}) exceeds 'MaxWorkGroupSize' ({}).", group_size, max_group_size));
+    }
+
+    Ok(())
+}
+
+/// Returns the list of intermediate languages accepted by `device` for use
+/// with `::create_program_with_il` (e.g. `["SPIR-V_1.0", "SPIR-V_1.2"]`).
+///
+/// Queries `DeviceInfo::IlsWithVersion` on OpenCL 3.0+ devices and
+/// `DeviceInfo::IlVersion` on OpenCL 2.1+ devices. Returns an empty list for
+/// earlier devices or devices without IL support.
+pub fn get_device_il_versions(device: &DeviceId) -> OclResult<Vec<String>> {
+    let version = device.version().chain_err(|| "::get_device_il_versions")?;
+
+    let request = if version >= OpenclVersion::new(3, 0) {
+        DeviceInfo::IlsWithVersion
+    } else if version >= OpenclVersion::CL21 {
+        DeviceInfo::IlVersion
+    } else {
+        return Ok(Vec::new());
+    };
+
+    match get_device_info(device, request) {
+        DeviceInfoResult::Error(err) => match *err.kind() {
+            OclErrorKind::EmptyInfoResult(_) => Ok(Vec::new()),
+            _ => Err(*err),
+        },
+        ils @ _ => ils.as_il_versions(),
+    }
+}
+
+/// Returns the execution capabilities of `device`.
+///
+/// `EXEC_KERNEL` is always set. `EXEC_NATIVE_KERNEL` indicates support for
+/// `::enqueue_native_kernel`.
+pub fn device_exec_capabilities<D: ClDeviceIdPtr>(device: D)
+        -> OclResult<DeviceExecCapabilities>
+{
+    match get_device_info(device, DeviceInfo::ExecutionCapabilities) {
+        DeviceInfoResult::ExecutionCapabilities(caps) => Ok(caps),
+        DeviceInfoResult::Error(err) => Err(*err),
+        _ => unreachable!(),
+    }
+}
+
+/// Returns the shared virtual memory (SVM) capabilities of `device`.
+///
+/// Devices older than OpenCL 2.0 have no SVM support and report an empty
+/// set rather than an error. Errors reported by 2.0+ devices are returned.
+pub fn get_device_svm_capabilities<D: ClDeviceIdPtr>(device: D)
+        -> OclResult<DeviceSvmCapabilities>
+{
+    match get_device_info(device, DeviceInfo::SvmCapabilities) {
+        DeviceInfoResult::SvmCapabilities(caps) => Ok(caps),
+        DeviceInfoResult::Error(err) => Err(*err).chain_err(|| "::get_device_svm_capabilities"),
+        _ => unreachable!(),
+    }
+}
+
+/// Returns the maximum number of work items in each dimension of a work
+/// group for `device`.
+///
+/// The length of the returned vector is equal to the number of dimensions
+/// reported by `DeviceInfo::MaxWorkItemDimensions`.
+pub fn get_device_max_work_item_sizes<D: ClDeviceIdPtr>(device: D) -> OclResult<Vec<usize>> {
+    match get_device_info(device, DeviceInfo::MaxWorkItemSizes) {
+        DeviceInfoResult::MaxWorkItemSizes(sizes) => Ok(sizes),
+        DeviceInfoResult::Error(err) => Err(*err).chain_err(|| "::get_device_max_work_item_sizes"),
+        _ => unreachable!(),
+    }
+}
+
+/// Returns a summary of the most commonly used properties of `device`.
+///
+/// An error identifying the property which could not be queried is
+/// returned if any query fails.
+pub fn get_device_info_all<D: ClDeviceIdPtr + Copy>(device: D) -> OclResult<DeviceSummary> {
+    macro_rules! query {
+        ($variant:ident) => (
+            match get_device_info(device, DeviceInfo::$variant) {
+                DeviceInfoResult::$variant(v) => v,
+                DeviceInfoResult::Error(err) => return Err::<DeviceSummary, _>(*err)
+                    .chain_err(|| format!("::get_device_info_all: DeviceInfo::{:?}",
+                        DeviceInfo::$variant)),
+                _ => unreachable!(),
+            }
+        )
+    }
+
+    Ok(DeviceSummary {
+        name: query!(Name),
+        vendor: query!(Vendor),
+        device_type: query!(Type),
+        max_compute_units: query!(MaxComputeUnits),
+        max_work_group_size: query!(MaxWorkGroupSize),
+        max_work_item_sizes: query!(MaxWorkItemSizes),
+        global_mem_size: query!(GlobalMemSize),
+        local_mem_size: query!(LocalMemSize),
+        max_clock_frequency: query!(MaxClockFrequency),
+        version: query!(Version),
+        extensions: query!(Extensions),
+    })
+}
+
 /// Returns the default platform if set by an environment variable or config
 /// file.
 pub fn default_platform_idx() -> usize {
@@ -3393,7 +5585,12 @@ pub fn default_platform_idx() -> usize {
 /// Returns the default or first platform.
 pub fn default_platform() -> OclResult<PlatformId> {
     let platform_list = try!(get_platform_ids());
+    select_default_platform(&platform_list)
+}
 
+/// Selects the default platform from `platform_list` (see
+/// `::default_platform`).
+pub fn select_default_platform(platform_list: &[PlatformId]) -> OclResult<PlatformId> {
     if platform_list.is_empty() {
         OclError::err_string("No platforms found!")
     } else {
@@ -3408,6 +5605,56 @@ pub fn default_platform() -> OclResult<PlatformId> {
     }
 }
 
+/// Returns the first platform whose name contains `substr` (case
+/// insensitive).
+///
+/// The error returned when no platform matches lists the names of all
+/// available platforms.
+pub fn get_platform_by_name(substr: &str) -> OclResult<PlatformId> {
+    let substr_lc = substr.to_lowercase();
+    let mut names = Vec::new();
+
+    for platform in get_platform_ids()? {
+        let name: String = match get_platform_info(&platform, PlatformInfo::Name) {
+            PlatformInfoResult::Error(err) => return Err(*err),
+            r @ _ => r.into(),
+        };
+
+        if name.to_lowercase().contains(&substr_lc) {
+            return Ok(platform);
+        }
+        names.push(name);
+    }
+
+    OclError::err_string(format!("::get_platform_by_name: No platform name contains '{}'. \
+        Available platforms: {:?}.", substr, names))
+}
+
+/// Returns the default device for `platform`: the first GPU or, if the
+/// platform has no GPUs, the first device of any type.
+///
+/// If the `OCL_DEFAULT_DEVICE_TYPE` environment variable is set (see
+/// `::default_device_type`), the first device of that type is returned
+/// instead.
+pub fn default_device<P: ClPlatformIdPtr>(platform: P) -> OclResult<DeviceId> {
+    let device_types = match env::var("OCL_DEFAULT_DEVICE_TYPE") {
+        Ok(_) => vec![default_device_type()?],
+        Err(_) => vec![::DEVICE_TYPE_GPU, ::DEVICE_TYPE_ALL],
+    };
+
+    for &device_type in device_types.iter() {
+        let devices = get_device_ids(platform, Some(device_type), Some(1))
+            .chain_err(|| "::default_device")?;
+
+        if let Some(&device) = devices.first() {
+            return Ok(device);
+        }
+    }
+
+    OclError::err_string(format!("::default_device: No devices of type(s) {:?} found.",
+        device_types))
+}
+
 /// Returns the default device type bitflags as specified by environment
 /// variable or else `DEVICE_TYPE_ALL`.
 pub fn default_device_type() -> OclResult<DeviceType> {
@@ -3451,6 +5698,208 @@ pub fn create_build_program<C, D>(
 }
 
 
+/// Fills `len` elements of `buffer`, starting at `offset`, with `value` and
+/// returns the event associated with the command.
+///
+/// `size_of::<T>()` must be a valid pattern size (1, 2, 4, 8, 16, 32, 64, or
+/// 128 bytes).
+///
+/// [Version Controlled: OpenCL 1.2+] See module docs for more info.
+pub fn fill_buffer<T: OclPrm>(command_queue: &CommandQueue, buffer: &Mem, value: T,
+            offset: usize, len: usize) -> OclResult<Event>
+{
+    let pattern_size = mem::size_of::<T>();
+
+    if !pattern_size.is_power_of_two() || pattern_size > 128 {
+        return OclError::err_string(format!("::fill_buffer: Invalid pattern size: {} bytes. \
+            The size of the pattern type must be one of 1, 2, 4, 8, 16, 32, 64, or 128.",
+            pattern_size));
+    }
+
+    let mut new_event = Event::null();
+    enqueue_fill_buffer(command_queue, buffer, value, offset, len, None::<Event>,
+        Some(&mut new_event), None).chain_err(|| "::fill_buffer")?;
+    Ok(new_event)
+}
+
+/// Copies `len` elements from `src_buffer` (starting at `src_offset`) to
+/// `dst_buffer` (starting at `dst_offset`) after all events in `wait_list`
+/// complete and returns the event associated with the command.
+pub fn enqueue_copy_buffer_ev<T, Ewl>(command_queue: &CommandQueue, src_buffer: &Mem,
+            dst_buffer: &Mem, src_offset: usize, dst_offset: usize, len: usize,
+            wait_list: Option<Ewl>) -> OclResult<Event>
+        where T: OclPrm, Ewl: ClWaitListPtr
+{
+    let mut new_event = Event::null();
+    enqueue_copy_buffer::<T, _, _, _>(command_queue, src_buffer, dst_buffer, src_offset,
+        dst_offset, len, wait_list, Some(&mut new_event))
+        .chain_err(|| "::enqueue_copy_buffer_ev")?;
+    Ok(new_event)
+}
+
+/// Maps `len` elements of `buffer`, starting at `offset`, into host memory,
+/// blocking until the map is complete.
+///
+/// The returned guard dereferences to a slice of the mapped region and
+/// unmaps it when dropped. See `MappedMem` for details.
+pub fn map_buffer<T: OclPrm>(command_queue: &CommandQueue, buffer: &Mem, map_flags: MapFlags,
+            offset: usize, len: usize) -> OclResult<MappedMem<T>>
+{
+    unsafe {
+        enqueue_map_buffer_guarded(command_queue, buffer, true, map_flags, offset, len,
+            None::<Event>, None::<&mut Event>).chain_err(|| "::map_buffer")
+    }
+}
+
+/// Reads a region of `image` into a newly allocated `Vec<T>`, blocking until
+/// the read is complete.
+///
+/// The length of the returned vector is determined from `region` and the
+/// image element (pixel) size, which must be a multiple of `size_of::<T>()`.
+pub fn read_image_to_vec<T: OclPrm>(command_queue: &CommandQueue, image: &Mem,
+            origin: [usize; 3], region: [usize; 3]) -> OclResult<Vec<T>>
+{
+    let element_size = match get_image_info(image, ImageInfo::ElementSize) {
+        ImageInfoResult::ElementSize(s) => s,
+        ImageInfoResult::Error(err) => return Err(*err),
+        _ => unreachable!(),
+    };
+
+    let t_size = mem::size_of::<T>();
+
+    if t_size == 0 || element_size % t_size != 0 {
+        return OclError::err_string(format!("::read_image_to_vec: The image element size ({} \
+            bytes) is not a multiple of the size of the destination type ({} bytes).",
+            element_size, t_size));
+    }
+
+    let len = region[0] * region[1] * region[2] * (element_size / t_size);
+    let mut vec = vec![T::default(); len];
+
+    unsafe {
+        enqueue_read_image(command_queue, image, true, origin, region, 0, 0, &mut vec,
+            None::<Event>, None::<&mut Event>).chain_err(|| "::read_image_to_vec")?;
+    }
+
+    Ok(vec)
+}
+
+/// Enqueues `kernel` as `enqueue_kernel` does and returns the event
+/// associated with the command.
+///
+/// If a sink has been registered for `command_queue` (see
+/// `set_profiling_sink`), `label` is passed to it as the kernel name along
+/// with the execution duration once the command completes. This allows
+/// durations to be aggregated by a semantic name (e.g. "forward_pass")
+/// rather than by the kernel function name. `label` is also used to
+/// identify the kernel in error messages.
+pub unsafe fn enqueue_kernel_named<Ewl: ClWaitListPtr>(
+            command_queue: &CommandQueue,
+            kernel: &Kernel,
+            label: &str,
+            work_dims: u32,
+            global_work_offset: Option<[usize; 3]>,
+            global_work_dims: &[usize; 3],
+            local_work_dims: Option<[usize; 3]>,
+            wait_list: Option<Ewl>,
+        ) -> OclResult<Event>
+{
+    let mut new_event = Event::null();
+    enqueue_kernel(command_queue, kernel, work_dims, global_work_offset, global_work_dims,
+        local_work_dims, wait_list, Some(&mut new_event))
+        .chain_err(|| format!("::enqueue_kernel_named: '{}'", label))?;
+
+    if let Some(sink) = command_queue.profiling_sink().cloned() {
+        let sink_label = label.to_owned();
+        set_event_callback_closure(&new_event, CommandExecutionStatus::Complete,
+            move |event, status| {
+                if status < 0 { return; }
+                if let Ok(duration) = profiling_duration_ns(&event) {
+                    sink.record(&sink_label, duration);
+                }
+            }).chain_err(|| format!("::enqueue_kernel_named: '{}'", label))?;
+    }
+
+    Ok(new_event)
+}
+
+/// Calls `f` up to `attempts` times, until it succeeds or returns an error
+/// which is not transient (see `Error::is_transient`).
+///
+/// The error from the final attempt is returned if all attempts fail.
+pub fn with_retry<T, F>(attempts: usize, f: F) -> OclResult<T>
+        where F: FnMut() -> OclResult<T>
+{
+    with_retry_if(attempts, OclError::is_transient, f)
+}
+
+/// Calls `f` up to `attempts` times, until it succeeds or returns an error
+/// for which `should_retry` returns false.
+pub fn with_retry_if<T, P, F>(attempts: usize, should_retry: P, mut f: F) -> OclResult<T>
+        where P: Fn(&OclError) -> bool, F: FnMut() -> OclResult<T>
+{
+    if attempts == 0 {
+        return OclError::err_string("::with_retry: At least one attempt is required.");
+    }
+
+    let mut attempt = 1;
+    loop {
+        match f() {
+            Err(ref err) if attempt < attempts && should_retry(err) => attempt += 1,
+            res => return res,
+        }
+    }
+}
+
+/// Returns the total, minimum, maximum, and mean execution durations (in
+/// nanoseconds) of `events`.
+///
+/// Each event must be complete and must have been enqueued on a command
+/// queue created with `QUEUE_PROFILING_ENABLE`. An error identifying the
+/// index of the first event lacking profiling info is returned otherwise.
+pub fn profiling_summary(events: &[Event]) -> OclResult<ProfilingSummary> {
+    if events.is_empty() {
+        return OclError::err_string("::profiling_summary: No events provided.");
+    }
+
+    let mut total = 0u64;
+    let mut min = u64::max_value();
+    let mut max = 0u64;
+
+    for (idx, event) in events.iter().enumerate() {
+        let duration = profiling_duration_ns(event)
+            .chain_err(|| format!("::profiling_summary: Event [{}]", idx))?;
+        total += duration;
+        if duration < min { min = duration; }
+        if duration > max { max = duration; }
+    }
+
+    Ok(ProfilingSummary {
+        count: events.len(),
+        total: total,
+        min: min,
+        max: max,
+        mean: total / events.len() as u64,
+    })
+}
+
+/// Returns the execution time of the command associated with `event`
+/// (`End - Start`), in nanoseconds.
+///
+/// The event must be complete and must have been enqueued on a command queue
+/// created with `QUEUE_PROFILING_ENABLE`.
+pub fn profiling_duration_ns<'e, E: ClEventPtrRef<'e>>(event: &'e E) -> OclResult<u64> {
+    let start = get_event_profiling_info(event, ProfilingInfo::Start).time();
+    let end = get_event_profiling_info(event, ProfilingInfo::End).time();
+
+    match (start, end) {
+        (Ok(s), Ok(e)) => Ok(e.saturating_sub(s)),
+        (Err(err), _) | (_, Err(err)) => Err::<u64, _>(err).chain_err(|| "::profiling_duration_ns: \
+            No profiling info is available for this event. Ensure the command queue was \
+            created with 'QUEUE_PROFILING_ENABLE' and that the event is complete."),
+    }
+}
+
 #[allow(dead_code)]
 /// Blocks until an event is complete.
 pub fn wait_for_event<'e, E: ClEventPtrRef<'e>>(event: &'e E) -> OclResult<()> {
@@ -3462,27 +5911,48 @@ pub fn wait_for_event<'e, E: ClEventPtrRef<'e>>(event: &'e E) -> OclResult<()> {
     eval_errcode(errcode, (), "clWaitForEvents", "")
 }
 
-/// Returns the status of `event`.
-pub fn event_status<'e, E: ClEventPtrRef<'e>>(event: &'e E) -> OclResult<CommandExecutionStatus> {
-    let mut status_int: cl_int = 0;
+/// Blocks until `event` is complete or `timeout` elapses, returning `false`
+/// in the latter case.
+///
+/// Completion is signalled by a callback registered on the event, leaving
+/// the implementation free to hang without taking the calling thread with
+/// it. On timeout, the callback remains registered and is freed whenever it
+/// eventually fires. Returns an error if the associated command terminated
+/// abnormally.
+pub fn wait_for_event_timeout<'e, E: ClEventPtrRef<'e>>(event: &'e E, timeout: Duration)
+        -> OclResult<bool>
+{
+    let pair = Arc::new((Mutex::new(None), Condvar::new()));
+    let pair_cb = pair.clone();
 
-    let errcode = unsafe {
-        ffi::clGetEventInfo(
-            *event.as_ptr_ref(),
-            ffi::CL_EVENT_COMMAND_EXECUTION_STATUS,
-            mem::size_of::<cl_int>(),
-            &mut status_int as *mut _ as *mut c_void,
-            ptr::null_mut(),
-        )
-    };
-    try!(eval_errcode(errcode, (), "clGetEventInfo", ""));
+    set_event_callback_closure(event, CommandExecutionStatus::Complete, move |_, status| {
+        let &(ref status_cell, ref cvar) = &*pair_cb;
+        if let Ok(mut status_cell) = status_cell.lock() {
+            *status_cell = Some(status);
+        }
+        cvar.notify_all();
+    })?;
 
-    CommandExecutionStatus::from_i32(status_int).ok_or_else(|| OclError::from("Error converting \
-        'clGetEventInfo' status output."))
+    let &(ref status_cell, ref cvar) = &*pair;
+    let deadline = Instant::now() + timeout;
+    let mut status = status_cell.lock().unwrap();
+
+    while status.is_none() {
+        let now = Instant::now();
+        if now >= deadline {
+            return Ok(false);
+        }
+        status = cvar.wait_timeout(status, deadline - now).unwrap().0;
+    }
+
+    match *status {
+        Some(s) => eval_event_status(s).map(|_| true),
+        None => unreachable!(),
+    }
 }
 
-/// Returns true if an event is complete, false if not complete.
-pub fn event_is_complete<'e, E: ClEventPtrRef<'e>>(event: &'e E) -> OclResult<bool> {
+// Queries the raw `CL_EVENT_COMMAND_EXECUTION_STATUS` of `event`.
+fn event_status_raw<'e, E: ClEventPtrRef<'e>>(event: &'e E) -> OclResult<cl_int> {
     let mut status_int: cl_int = 0;
 
     let errcode = unsafe {
@@ -3498,12 +5968,56 @@ pub fn event_is_complete<'e, E: ClEventPtrRef<'e>>(event: &'e E) -> OclResult<bo
     #[cfg(feature = "event_debug_print")]
     unsafe {
         println!("Event Status: {:?} (ptr: {:?})",
-            CommandExecutionStatus::from_i32(status_int).unwrap(),
+            EventStatus::from_i32(status_int),
             *event.as_ptr_ref());
     }
 
-    eval_errcode(errcode, status_int == CommandExecutionStatus::Complete as i32,
-        "clEventGetInfo", "CL_EVENT_COMMAND_EXECUTION_STATUS")
+    eval_errcode(errcode, status_int, "clGetEventInfo", "CL_EVENT_COMMAND_EXECUTION_STATUS")
+}
+
+/// Returns the execution status of the command associated with `event`
+/// without blocking.
+///
+/// A command which was terminated abnormally reports `EventStatus::Error`
+/// containing its (negative) error code. An `Err` is returned only if the
+/// status can not be queried.
+pub fn get_event_status<'e, E: ClEventPtrRef<'e>>(event: &'e E) -> OclResult<EventStatus> {
+    let status_int = event_status_raw(event)?;
+
+    EventStatus::from_i32(status_int).ok_or_else(|| OclError::from("Error converting \
+        'clGetEventInfo' status output."))
+}
+
+/// Returns the status of `event`.
+///
+/// If the command associated with `event` was terminated abnormally, the
+/// (negative) error code reported in place of a status is returned as an
+/// `Error::Status`. Use `::get_event_status` to distinguish this from a
+/// failed query.
+pub fn event_status<'e, E: ClEventPtrRef<'e>>(event: &'e E) -> OclResult<CommandExecutionStatus> {
+    let status_int = event_status_raw(event)?;
+    try!(eval_event_status(status_int));
+
+    CommandExecutionStatus::from_i32(status_int).ok_or_else(|| OclError::from("Error converting \
+        'clGetEventInfo' status output."))
+}
+
+// Returns the error code reported by an abnormally terminated command.
+fn eval_event_status(status_int: cl_int) -> OclResult<()> {
+    if status_int < 0 {
+        eval_errcode(status_int, (), "clGetEventInfo", "CL_EVENT_COMMAND_EXECUTION_STATUS: \
+            Command terminated abnormally.")
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns true if an event is complete, false if not complete.
+///
+/// A command which was terminated abnormally is not complete (see
+/// `::get_event_status`).
+pub fn event_is_complete<'e, E: ClEventPtrRef<'e>>(event: &'e E) -> OclResult<bool> {
+    event_status_raw(event).map(|status_int| status_int == CommandExecutionStatus::Complete as i32)
 }
 
 
@@ -3544,11 +6058,7 @@ pub fn verify_context<C>(context: C) -> OclResult<()>
 
 /// Checks to see if a device supports the `CL_GL_SHARING_EXT` extension.
 fn device_supports_cl_gl_sharing<D: ClDeviceIdPtr>(device: D) -> OclResult<bool> {
-    match get_device_info(device, DeviceInfo::Extensions) {
-        DeviceInfoResult::Extensions(extensions) => Ok(extensions.contains(CL_GL_SHARING_EXT)),
-        DeviceInfoResult::Error(err) => Err(*err),
-        _ => unreachable!(),
-    }
+    device_has_extension(device, CL_GL_SHARING_EXT)
 }
 
 
@@ -121,45 +121,62 @@ pub use self::error::{Error, Result, ErrorKind};
 
 pub use self::types::abs::{ClWaitListPtr, ClNullEventPtr, ClEventPtrRef, ClPlatformIdPtr,
     ClDeviceIdPtr, ClContextPtr, EventRefWrapper, PlatformId, DeviceId, Context, CommandQueue, Mem,
-    Program, Kernel, Event, Sampler, ClVersions, AsMem, MemCmdRw, MemCmdAll, MemMap};
+    Program, Kernel, Event, Sampler, ClVersions, AsMem, MemCmdRw, MemCmdAll, MemMap,
+    MappedMem, CachedReader, EventList};
 
 pub use self::types::structs::{self, OpenclVersion, ContextProperties, ImageFormatParseError,
-    ImageFormatParseResult, ImageFormat, ImageDescriptor, BufferRegion, ContextPropertyValue};
+    ImageFormatParseResult, ImageFormat, ImageDescriptor, BufferRegion, ContextPropertyValue,
+    ProfilingSummary, ProfilingSink, SamplerProperties, ClHalf, DeviceSummary};
 
 pub use self::types::enums::{EmptyInfoResult, KernelArg, PlatformInfoResult, DeviceInfoResult,
     ContextInfoResult, GlContextInfoResult, CommandQueueInfoResult, MemInfoResult, ImageInfoResult,
     SamplerInfoResult, ProgramInfoResult, ProgramBuildInfoResult, KernelInfoResult,
-    KernelArgInfoResult, KernelWorkGroupInfoResult, EventInfoResult, ProfilingInfoResult};
+    KernelArgInfoResult, KernelWorkGroupInfoResult, EventInfoResult, ProfilingInfoResult,
+    Profile, DevicePartition, GlTextureInfoResult, EventStatus};
 
 pub use self::functions::{get_platform_ids, get_platform_info, get_device_ids, get_device_info,
     create_sub_devices, retain_device, release_device, create_context, create_context_from_type,
-    retain_context, release_context, get_context_info, get_gl_context_info_khr,
+    create_context_with_callback, retain_context, release_context, get_context_info,
+    get_gl_context_info_khr,
     create_command_queue, retain_command_queue, release_command_queue, get_command_queue_info,
     create_buffer, create_sub_buffer, create_image, retain_mem_object, release_mem_object,
     get_supported_image_formats, get_mem_object_info, get_image_info,
     set_mem_object_destructor_callback, create_sampler, retain_sampler, release_sampler,
-    get_sampler_info, create_program_with_source, create_program_with_binary,
-    create_program_with_built_in_kernels, retain_program, release_program, build_program,
-    compile_program, link_program, create_build_program, get_program_info, get_program_build_info,
-    create_kernel, create_kernels_in_program, retain_kernel, release_kernel, set_kernel_arg,
+    get_sampler_info, create_program_with_source, create_program_with_sources,
+    create_program_with_binary, create_program_with_binary_broadcast,
+    create_program_with_built_in_kernels, retain_program,
+    release_program, build_program, compile_program, link_program, create_build_program,
+    get_program_info, get_program_build_info, get_program_binaries, create_kernel,
+    create_kernels_in_program, retain_kernel, release_kernel, set_kernel_arg,
     get_kernel_info, get_kernel_arg_info, get_kernel_work_group_info, wait_for_events,
     get_event_info, create_user_event, retain_event, release_event, set_user_event_status,
     set_event_callback, get_event_profiling_info, flush, finish, enqueue_read_buffer,
     enqueue_read_buffer_rect, enqueue_write_buffer, enqueue_write_buffer_rect, enqueue_copy_buffer,
     create_from_gl_buffer, create_from_gl_renderbuffer, create_from_gl_texture,
-    create_from_gl_texture_2d, create_from_gl_texture_3d, enqueue_fill_buffer,
+    create_from_gl_texture_2d, create_from_gl_texture_3d, get_gl_object_info,
+    get_gl_texture_info, enqueue_fill_buffer,
     enqueue_copy_buffer_rect, enqueue_acquire_gl_objects, enqueue_release_gl_objects,
     enqueue_read_image, enqueue_write_image, enqueue_fill_image, enqueue_copy_image,
     enqueue_copy_image_to_buffer, enqueue_copy_buffer_to_image, enqueue_map_buffer,
     enqueue_map_image, enqueue_unmap_mem_object, enqueue_migrate_mem_objects, enqueue_kernel,
-    enqueue_task, enqueue_native_kernel, enqueue_marker_with_wait_list,
-    enqueue_barrier_with_wait_list, get_extension_function_address_for_platform, wait_for_event,
-    event_status, default_platform_idx, program_build_err, verify_context, default_platform,
+    enqueue_kernel_ndrange, enqueue_kernel_auto, enqueue_task, enqueue_native_kernel,
+    enqueue_marker_with_wait_list, enqueue_barrier_with_wait_list,
+    get_extension_function_address_for_platform, wait_for_event, event_status, get_event_status,
+    default_platform_idx, program_build_err, verify_context, default_platform,
     default_device_type, device_versions, event_is_complete, _dummy_event_callback,
-    _complete_user_event, get_context_platform};
+    _complete_user_event, get_context_platform, set_event_callback_closure, wait_for_event_timeout,
+    enqueue_marker, read_image_to_vec, device_profile, device_supports_int64, device_has_fp16,
+    map_buffer, create_context_single, fill_buffer, profiling_summary, profiling_duration_ns,
+    device_exec_capabilities, enqueue_map_buffer_guarded, build_program_with_timeout,
+    get_device_il_versions, enqueue_copy_buffer_ev, validate_work_dims, default_device,
+    get_platform_by_name, set_profiling_sink, enqueue_kernel_named, require_device_version,
+    require_platform_version, create_buffer_from_slice, build_program_async, with_retry,
+    with_retry_if, get_program_build_log, get_device_info_all,
+    get_device_max_work_item_sizes, create_host_buffer, get_device_svm_capabilities,
+    get_device_extensions, get_platform_extensions, device_has_extension};
 
 #[allow(deprecated)]
-pub use self::functions::{enqueue_acquire_gl_buffer, enqueue_release_gl_buffer};
+pub use self::functions::{enqueue_acquire_gl_buffer, enqueue_release_gl_buffer, unload_compiler};
 
 pub use traits::{OclPrm, OclNum, OclScl};
 
@@ -181,8 +198,16 @@ pub use self::vector::{
 #[cfg(feature = "ocl-core-vector")]
 pub use traits::OclVec;
 
+#[cfg(feature = "opencl_version_1_2")]
+pub use self::functions::unload_platform_compiler;
+
+#[cfg(feature = "opencl_version_2_0")]
+pub use self::functions::{create_sampler_with_properties, enqueue_svm_memcpy, enqueue_svm_map,
+    enqueue_svm_unmap, enqueue_svm_mem_fill, enqueue_svm_free, create_pipe, get_pipe_info};
+
 #[cfg(feature = "opencl_version_2_1")]
-pub use self::functions::{create_program_with_il};
+pub use self::functions::{create_program_with_il, get_device_and_host_timer, get_host_timer,
+    set_default_device_command_queue};
 
 
 
@@ -205,6 +230,7 @@ pub type CreateContextCallbackFn = extern "C" fn (*const libc::c_char, *const li
     libc::size_t, *mut libc::c_void);
 pub type BuildProgramCallbackFn = extern "C" fn (*mut libc::c_void, *mut libc::c_void);
 pub type UserDataPtr = *mut libc::c_void;
+pub type ContextCallback = Box<FnMut(&str, &[u8]) + Send>;
 
 //=============================================================================
 //================================== TRAITS ===================================
@@ -230,7 +256,7 @@ mod traits {
     /// A primitive type usable within `OpenCL` kernels.
     ///
     /// Includes all of the signed, unsigned, and floating point 8 bit - 64 bit
-    /// scalar primitives (ex.: cl_char, cl_uint, cl_double) (exception: cl_half)
+    /// scalar primitives (ex.: cl_char, cl_uint, cl_double) (cl_half: see `ClHalf`)
     /// and their vector counterparts (ex.: cl_int4, cl_float3, cl_short16);
     ///
     /// Can also be implemented for custom types as long as layout and
@@ -393,6 +419,17 @@ bitflags! {
 }
 
 
+bitflags! {
+    /// cl_device_svm_capabilities - bitfield
+    pub flags DeviceSvmCapabilities: u64 {
+        const SVM_COARSE_GRAIN_BUFFER = 1 << 0,
+        const SVM_FINE_GRAIN_BUFFER = 1 << 1,
+        const SVM_FINE_GRAIN_SYSTEM = 1 << 2,
+        const SVM_ATOMICS = 1 << 3,
+    }
+}
+
+
 bitflags! {
     /// cl_command_queue_properties - bitfield
     pub flags CommandQueueProperties: u64 {
@@ -407,12 +444,20 @@ impl CommandQueueProperties {
     #[inline] pub fn new() -> CommandQueueProperties { CommandQueueProperties::empty() }
     #[inline] pub fn out_of_order(self) -> CommandQueueProperties { self | QUEUE_OUT_OF_ORDER_EXEC_MODE_ENABLE }
     #[inline] pub fn profiling(self) -> CommandQueueProperties { self | QUEUE_PROFILING_ENABLE }
+    /// On-device queues must also be out-of-order, both flags are set.
+    #[inline] pub fn on_device(self) -> CommandQueueProperties { self | QUEUE_OUT_OF_ORDER_EXEC_MODE_ENABLE | QUEUE_ON_DEVICE }
+    #[inline] pub fn on_device_default(self) -> CommandQueueProperties { self.on_device() | QUEUE_ON_DEVICE_DEFAULT }
 }
 
 impl Default for CommandQueueProperties {
     #[inline] fn default() -> CommandQueueProperties { CommandQueueProperties::empty() }
 }
 
+impl From<u64> for CommandQueueProperties {
+    /// Converts raw `cl_command_queue_properties` bits, discarding unknown bits.
+    #[inline] fn from(bits: u64) -> CommandQueueProperties { CommandQueueProperties::from_bits_truncate(bits) }
+}
+
 
 bitflags! {
     /// cl_device_affinity_domain
@@ -440,6 +485,8 @@ bitflags! {
         const MEM_HOST_WRITE_ONLY = 1 << 7,
         const MEM_HOST_READ_ONLY = 1 << 8,
         const MEM_HOST_NO_ACCESS = 1 << 9,
+        // CL_MEM_KERNEL_READ_AND_WRITE (2.0):
+        const MEM_KERNEL_READ_AND_WRITE = 1 << 12,
     }
 }
 
@@ -454,6 +501,38 @@ impl MemFlags {
     #[inline] pub fn host_write_only(self) -> MemFlags { self | MEM_HOST_WRITE_ONLY }
     #[inline] pub fn host_read_only(self) -> MemFlags { self | MEM_HOST_READ_ONLY }
     #[inline] pub fn host_no_access(self) -> MemFlags { self | MEM_HOST_NO_ACCESS }
+    #[inline] pub fn kernel_read_and_write(self) -> MemFlags { self | MEM_KERNEL_READ_AND_WRITE }
+
+    /// Returns an error if this set of flags contains a combination which
+    /// is illegal for memory object creation:
+    ///
+    /// * More than one of `MEM_READ_WRITE`, `MEM_WRITE_ONLY`, and `MEM_READ_ONLY`.
+    /// * `MEM_USE_HOST_PTR` with either `MEM_ALLOC_HOST_PTR` or `MEM_COPY_HOST_PTR`.
+    /// * More than one of `MEM_HOST_WRITE_ONLY`, `MEM_HOST_READ_ONLY`, and
+    ///   `MEM_HOST_NO_ACCESS`.
+    pub fn validate(&self) -> Result<()> {
+        let exclusive_sets = [
+            (MEM_READ_WRITE | MEM_WRITE_ONLY | MEM_READ_ONLY, "kernel access"),
+            (MEM_HOST_WRITE_ONLY | MEM_HOST_READ_ONLY | MEM_HOST_NO_ACCESS, "host access"),
+        ];
+
+        for &(set, desc) in exclusive_sets.iter() {
+            if (*self & set).bits().count_ones() > 1 {
+                return Error::err_conversion(format!("MemFlags::validate: Only one {} \
+                    flag may be specified (flags: {:?}).", desc, *self));
+            }
+        }
+
+        if self.contains(MEM_USE_HOST_PTR) &&
+            self.intersects(MEM_ALLOC_HOST_PTR | MEM_COPY_HOST_PTR)
+        {
+            return Error::err_conversion(format!("MemFlags::validate: 'MEM_USE_HOST_PTR' \
+                may not be combined with 'MEM_ALLOC_HOST_PTR' or 'MEM_COPY_HOST_PTR' \
+                (flags: {:?}).", *self));
+        }
+
+        Ok(())
+    }
 }
 
 impl Default for MemFlags {
@@ -484,6 +563,17 @@ impl MapFlags {
     #[inline] pub fn read(self) -> MapFlags { self | MAP_READ }
     #[inline] pub fn write(self) -> MapFlags { self | MAP_WRITE }
     #[inline] pub fn write_invalidate_region(self) -> MapFlags { self | MAP_WRITE_INVALIDATE_REGION }
+
+    /// Returns an error with the `Conversion` kind if `MAP_WRITE_INVALIDATE_REGION`
+    /// is combined with `MAP_READ` or `MAP_WRITE`, which is not permitted.
+    pub fn validate(&self) -> Result<()> {
+        if self.contains(MAP_WRITE_INVALIDATE_REGION) && self.intersects(MAP_READ | MAP_WRITE) {
+            return Error::err_conversion(format!("MapFlags::validate: \
+                'MAP_WRITE_INVALIDATE_REGION' may not be combined with 'MAP_READ' or \
+                'MAP_WRITE' (flags: {:?}).", *self));
+        }
+        Ok(())
+    }
 }
 
 impl Default for MapFlags {
@@ -591,6 +681,122 @@ enum_from_primitive! {
     }
 }
 
+impl Status {
+    /// Returns true if this status is `CL_SUCCESS`.
+    #[inline]
+    pub fn is_success(&self) -> bool {
+        *self == Status::CL_SUCCESS
+    }
+
+    /// Returns true if this status is anything other than `CL_SUCCESS`.
+    #[inline]
+    pub fn is_error(&self) -> bool {
+        !self.is_success()
+    }
+
+    /// Returns true if this status indicates that host or device memory (or
+    /// other resources) were exhausted: `CL_OUT_OF_HOST_MEMORY`,
+    /// `CL_OUT_OF_RESOURCES`, or `CL_MEM_OBJECT_ALLOCATION_FAILURE`.
+    pub fn is_out_of_memory(&self) -> bool {
+        match *self {
+            Status::CL_OUT_OF_HOST_MEMORY
+            | Status::CL_OUT_OF_RESOURCES
+            | Status::CL_MEM_OBJECT_ALLOCATION_FAILURE => true,
+            _ => false,
+        }
+    }
+
+    /// Returns true if this status indicates an invalid object handle
+    /// (command queue, context, device, memory object, kernel, program,
+    /// sampler, or event).
+    ///
+    /// These usually point to the use of an already released object or of
+    /// an object belonging to another context rather than an invalid value.
+    pub fn is_invalid_object(&self) -> bool {
+        match *self {
+            Status::CL_INVALID_COMMAND_QUEUE
+            | Status::CL_INVALID_CONTEXT
+            | Status::CL_INVALID_MEM_OBJECT
+            | Status::CL_INVALID_KERNEL
+            | Status::CL_INVALID_EVENT
+            | Status::CL_INVALID_DEVICE
+            | Status::CL_INVALID_PROGRAM
+            | Status::CL_INVALID_SAMPLER => true,
+            _ => false,
+        }
+    }
+
+    /// Returns true if this status indicates a condition which may clear up
+    /// on its own, making the failed call eligible to be retried.
+    ///
+    /// Transient statuses are: `CL_OUT_OF_RESOURCES`, `CL_OUT_OF_HOST_MEMORY`,
+    /// `CL_MEM_OBJECT_ALLOCATION_FAILURE`, and `CL_DEVICE_NOT_AVAILABLE`. All
+    /// others, notably the `CL_INVALID_*` statuses which indicate programming
+    /// errors, are not.
+    pub fn is_transient(&self) -> bool {
+        self.is_out_of_memory() || *self == Status::CL_DEVICE_NOT_AVAILABLE
+    }
+
+    /// Returns a short, stable category name for this status, suitable for
+    /// aggregating errors (e.g. in metrics).
+    ///
+    /// One of: `"success"`, `"memory"`, `"argument"`, `"object"`, `"build"`,
+    /// `"profiling"`, or `"other"`.
+    pub fn category(&self) -> &'static str {
+        if self.is_invalid_object() { return "object"; }
+
+        match *self {
+            Status::CL_SUCCESS => "success",
+            Status::CL_MEM_OBJECT_ALLOCATION_FAILURE
+            | Status::CL_OUT_OF_RESOURCES
+            | Status::CL_OUT_OF_HOST_MEMORY
+            | Status::CL_MEM_COPY_OVERLAP
+            | Status::CL_MAP_FAILURE
+            | Status::CL_MISALIGNED_SUB_BUFFER_OFFSET
+            | Status::CL_NV_INVALID_MEM_ACCESS => "memory",
+            Status::CL_INVALID_VALUE
+            | Status::CL_INVALID_DEVICE_TYPE
+            | Status::CL_INVALID_QUEUE_PROPERTIES
+            | Status::CL_INVALID_HOST_PTR
+            | Status::CL_INVALID_IMAGE_FORMAT_DESCRIPTOR
+            | Status::CL_INVALID_IMAGE_SIZE
+            | Status::CL_INVALID_ARG_INDEX
+            | Status::CL_INVALID_ARG_VALUE
+            | Status::CL_INVALID_ARG_SIZE
+            | Status::CL_INVALID_KERNEL_ARGS
+            | Status::CL_INVALID_WORK_DIMENSION
+            | Status::CL_INVALID_WORK_GROUP_SIZE
+            | Status::CL_INVALID_WORK_ITEM_SIZE
+            | Status::CL_INVALID_GLOBAL_OFFSET
+            | Status::CL_INVALID_EVENT_WAIT_LIST
+            | Status::CL_INVALID_BUFFER_SIZE
+            | Status::CL_INVALID_MIP_LEVEL
+            | Status::CL_INVALID_GLOBAL_WORK_SIZE
+            | Status::CL_INVALID_PROPERTY
+            | Status::CL_INVALID_IMAGE_DESCRIPTOR
+            | Status::CL_INVALID_DEVICE_PARTITION_COUNT
+            | Status::CL_INVALID_PIPE_SIZE => "argument",
+            Status::CL_INVALID_PLATFORM
+            | Status::CL_INVALID_GL_OBJECT
+            | Status::CL_INVALID_DEVICE_QUEUE => "object",
+            Status::CL_COMPILER_NOT_AVAILABLE
+            | Status::CL_BUILD_PROGRAM_FAILURE
+            | Status::CL_COMPILE_PROGRAM_FAILURE
+            | Status::CL_LINKER_NOT_AVAILABLE
+            | Status::CL_LINK_PROGRAM_FAILURE
+            | Status::CL_INVALID_BINARY
+            | Status::CL_INVALID_BUILD_OPTIONS
+            | Status::CL_INVALID_PROGRAM_EXECUTABLE
+            | Status::CL_INVALID_KERNEL_NAME
+            | Status::CL_INVALID_KERNEL_DEFINITION
+            | Status::CL_INVALID_COMPILER_OPTIONS
+            | Status::CL_INVALID_LINKER_OPTIONS => "build",
+            Status::CL_PROFILING_INFO_NOT_AVAILABLE => "profiling",
+            _ => "other",
+        }
+    }
+}
+
 impl std::fmt::Display for Status {
     fn fmt(&self, fmtr: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(fmtr, "{:?}", self)
@@ -638,7 +844,7 @@ enum_from_primitive! {
 enum_from_primitive! {
     /// Specifies the number of channels and the channel layout i.e. the memory layout in which channels are stored in the image. Valid values are described in the table below. (from SDK)
     #[repr(C)]
-    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
     pub enum ImageChannelOrder {
         R = ffi::CL_R as isize,
         A = ffi::CL_A as isize,
@@ -667,7 +873,7 @@ enum_from_primitive! {
 enum_from_primitive! {
     /// Describes the size of the channel data type. The number of bits per element determined by the image_channel_data_type and image_channel_order must be a power of two. The list of supported values is described in the table below. (from SDK)
     #[repr(C)]
-    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
     pub enum ImageChannelDataType {
         // Each channel component is a normalized signed 8-bit integer value:
         SnormInt8 = ffi::CL_SNORM_INT8 as isize,
@@ -737,6 +943,10 @@ enum_from_primitive! {
         Name = ffi::CL_PLATFORM_NAME as isize,
         Vendor = ffi::CL_PLATFORM_VENDOR as isize,
         Extensions = ffi::CL_PLATFORM_EXTENSIONS as isize,
+        // CL_PLATFORM_HOST_TIMER_RESOLUTION (2.1):
+        HostTimerResolution = 0x0905,
+        // CL_PLATFORM_EXTENSIONS_WITH_VERSION (3.0):
+        ExtensionsWithVersion = 0x0907,
     }
 }
 
@@ -822,6 +1032,12 @@ enum_from_primitive! {
         PrintfBufferSize = ffi::CL_DEVICE_PRINTF_BUFFER_SIZE as isize,
         ImagePitchAlignment = ffi::CL_DEVICE_IMAGE_PITCH_ALIGNMENT as isize,
         ImageBaseAddressAlignment = ffi::CL_DEVICE_IMAGE_BASE_ADDRESS_ALIGNMENT as isize,
+        // CL_DEVICE_SVM_CAPABILITIES (2.0):
+        SvmCapabilities = 0x1053,
+        // CL_DEVICE_IL_VERSION (2.1):
+        IlVersion = 0x105B,
+        // CL_DEVICE_ILS_WITH_VERSION (3.0):
+        IlsWithVersion = 0x1061,
     }
 }
 
@@ -876,6 +1092,17 @@ enum_from_primitive! {
 }
 
 
+enum_from_primitive! {
+    /// cl_gl_texture_info
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum GlTextureInfo {
+        TextureTarget = ffi::CL_GL_TEXTURE_TARGET as isize,
+        MipmapLevel = ffi::CL_GL_MIPMAP_LEVEL as isize,
+    }
+}
+
+
 // [TODO]: Do proper auto-detection of available OpenGL context type.
 #[cfg(target_os = "macos")]
 const CL_CGL_SHAREGROUP_KHR_OS_SPECIFIC: isize = ffi::CL_CONTEXT_PROPERTY_USE_CGL_SHAREGROUP_APPLE;
@@ -941,6 +1168,8 @@ enum_from_primitive! {
         Device = ffi::CL_QUEUE_DEVICE as isize,
         ReferenceCount = ffi::CL_QUEUE_REFERENCE_COUNT as isize,
         Properties = ffi::CL_QUEUE_PROPERTIES as isize,
+        // CL_QUEUE_SIZE (2.0):
+        Size = 0x1094,
     }
 }
 
@@ -982,6 +1211,8 @@ enum_from_primitive! {
         Image1d = ffi::CL_MEM_OBJECT_IMAGE1D as isize,
         Image1dArray = ffi::CL_MEM_OBJECT_IMAGE1D_ARRAY as isize,
         Image1dBuffer = ffi::CL_MEM_OBJECT_IMAGE1D_BUFFER as isize,
+        // CL_MEM_OBJECT_PIPE (2.0):
+        Pipe = 0x10F7,
     }
 }
 
@@ -1024,6 +1255,17 @@ enum_from_primitive! {
 }
 
 
+enum_from_primitive! {
+    /// cl_pipe_info (2.0)
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum PipeInfo {
+        PacketSize = 0x1120,
+        MaxPackets = 0x1121,
+    }
+}
+
+
 enum_from_primitive! {
     /// cl_addressing_mode
     #[repr(C)]
@@ -1059,6 +1301,12 @@ enum_from_primitive! {
         NormalizedCoords = ffi::CL_SAMPLER_NORMALIZED_COORDS as isize,
         AddressingMode = ffi::CL_SAMPLER_ADDRESSING_MODE as isize,
         FilterMode = ffi::CL_SAMPLER_FILTER_MODE as isize,
+        // CL_SAMPLER_MIP_FILTER_MODE (cl_khr_mipmap_image):
+        MipFilterMode = 0x1155,
+        // CL_SAMPLER_LOD_MIN (cl_khr_mipmap_image):
+        LodMin = 0x1156,
+        // CL_SAMPLER_LOD_MAX (cl_khr_mipmap_image):
+        LodMax = 0x1157,
     }
 }
 
@@ -1090,6 +1338,8 @@ enum_from_primitive! {
         BuildOptions = ffi::CL_PROGRAM_BUILD_OPTIONS as isize,
         BuildLog = ffi::CL_PROGRAM_BUILD_LOG as isize,
         BinaryType = ffi::CL_PROGRAM_BINARY_TYPE as isize,
+        // CL_PROGRAM_BUILD_GLOBAL_VARIABLE_TOTAL_SIZE (2.0):
+        GlobalVariableTotalSize = 0x1185,
     }
 }
 
@@ -1262,5 +1512,7 @@ enum_from_primitive! {
         Submit = ffi::CL_PROFILING_COMMAND_SUBMIT as isize,
         Start = ffi::CL_PROFILING_COMMAND_START as isize,
         End = ffi::CL_PROFILING_COMMAND_END as isize,
+        // CL_PROFILING_COMMAND_COMPLETE (2.0):
+        Complete = 0x1284,
     }
 }
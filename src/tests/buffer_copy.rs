@@ -71,3 +71,53 @@ fn buffer_copy_core() {
         }
     }
 }
+
+#[test]
+fn buffer_copy_rejects_image() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context_properties = ::ContextProperties::new().platform(platform_id);
+    let context = ::create_context(Some(&context_properties),
+        &[device], None, None).unwrap();
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+
+    let buffer = unsafe { ::create_buffer::<_, u8>(&context, ::MEM_READ_WRITE, 64 * 4, None)
+        .unwrap() };
+    let image_desc = ::ImageDescriptor::new(::MemObjectType::Image2d, 8, 8, 1, 0, 0, 0, None);
+    let image = unsafe { ::create_image::<_, u8>(&context, ::MEM_READ_WRITE,
+        &::ImageFormat::new_rgba(), &image_desc, None, None).unwrap() };
+
+    assert_eq!(image.mem_type().unwrap(), ::MemObjectType::Image2d);
+
+    let res = ::enqueue_copy_buffer::<u8, _, _, _>(&queue, &image, &buffer, 0, 0, 64,
+        None::<::Event>, None::<&mut ::Event>);
+    assert!(res.is_err());
+}
+
+#[test]
+fn buffer_copy_ev() {
+    const LEN: usize = 4096;
+
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+
+    let src_vec: Vec<u32> = (0..LEN as u32).collect();
+    let src_buffer = unsafe { ::create_buffer(&context, ::MEM_READ_WRITE |
+        ::MEM_COPY_HOST_PTR, LEN, Some(&src_vec)).unwrap() };
+    let dst_buffer = unsafe { ::create_buffer::<_, u32>(&context, ::MEM_READ_WRITE, LEN,
+        None).unwrap() };
+
+    let copy_event = ::enqueue_copy_buffer_ev::<u32, ::Event>(&queue, &src_buffer, &dst_buffer,
+        0, 0, LEN, None).unwrap();
+    ::wait_for_event(&copy_event).unwrap();
+    assert!(::event_is_complete(&copy_event).unwrap());
+
+    let mut dst_vec = vec![0u32; LEN];
+    unsafe { ::enqueue_read_buffer(&queue, &dst_buffer, true, 0, &mut dst_vec,
+        None::<::Event>, None::<&mut ::Event>).unwrap() };
+    assert_eq!(dst_vec, src_vec);
+}
@@ -54,6 +54,90 @@ fn fill() {
     //     assert_eq!(ele, 15.0f32);
     // }
 }
+
+#[test]
+fn fill_out_of_order_joined_by_marker() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context_properties = ::ContextProperties::new().platform(platform_id);
+    let context = ::create_context(Some(&context_properties),
+        &[device], None, None).unwrap();
+
+    // Fall back to an in-order queue if out-of-order execution is unsupported:
+    let queue = ::create_command_queue(&context, &device,
+            Some(::QUEUE_OUT_OF_ORDER_EXEC_MODE_ENABLE))
+        .or_else(|_| ::create_command_queue(&context, &device, None)).unwrap();
+
+    let buffer_a = unsafe { ::create_buffer::<_, f32>(&context, ::MEM_READ_WRITE, DATASET_SIZE, None).unwrap() };
+    let buffer_b = unsafe { ::create_buffer::<_, f32>(&context, ::MEM_READ_WRITE, DATASET_SIZE, None).unwrap() };
+
+    let mut fill_events = [::Event::null(), ::Event::null()];
+    ::enqueue_fill_buffer::<f32, _, _, _>(&queue, &buffer_a, 3.0f32, 0, DATASET_SIZE,
+        None::<::Event>, Some(&mut fill_events[0]), None).unwrap();
+    ::enqueue_fill_buffer::<f32, _, _, _>(&queue, &buffer_b, 7.0f32, 0, DATASET_SIZE,
+        None::<::Event>, Some(&mut fill_events[1]), None).unwrap();
+
+    let mut marker = ::Event::null();
+    ::enqueue_marker_with_wait_list(&queue, Some(&fill_events[..]), Some(&mut marker), None)
+        .unwrap();
+    ::wait_for_event(&marker).unwrap();
+
+    assert!(::event_is_complete(&fill_events[0]).unwrap());
+    assert!(::event_is_complete(&fill_events[1]).unwrap());
+
+    let mut vec = vec![0.0f32; DATASET_SIZE];
+    unsafe { ::enqueue_read_buffer::<f32, _, _, _>(&queue, &buffer_a, true, 0, &mut vec,
+        None::<::Event>, None::<&mut ::Event>).unwrap() };
+    assert!(vec.iter().all(|x| *x == 3.0f32));
+    unsafe { ::enqueue_read_buffer::<f32, _, _, _>(&queue, &buffer_b, true, 0, &mut vec,
+        None::<::Event>, None::<&mut ::Event>).unwrap() };
+    assert!(vec.iter().all(|x| *x == 7.0f32));
+}
+
+#[test]
+fn fill_buffer_u32() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+
+    let buffer = unsafe { ::create_buffer::<_, u32>(&context, ::MEM_READ_WRITE, DATASET_SIZE, None).unwrap() };
+
+    let fill_event = ::fill_buffer(&queue, &buffer, 42u32, 0, DATASET_SIZE).unwrap();
+    ::wait_for_event(&fill_event).unwrap();
+
+    let mut vec = vec![0u32; DATASET_SIZE];
+    unsafe { ::enqueue_read_buffer(&queue, &buffer, true, 0, &mut vec,
+        None::<::Event>, None::<&mut ::Event>).unwrap() };
+    assert!(vec.iter().all(|&x| x == 42));
+}
+
+#[test]
+fn fill_then_finish() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+
+    let buffer = unsafe { ::create_buffer::<_, u32>(&context, ::MEM_READ_WRITE, DATASET_SIZE, None).unwrap() };
+
+    let mut fill_event = ::Event::null();
+    ::enqueue_fill_buffer::<u32, _, _, _>(&queue, &buffer, 17, 0, DATASET_SIZE,
+        None::<::Event>, Some(&mut fill_event), None).unwrap();
+    ::flush(&queue).unwrap();
+    ::finish(&queue).unwrap();
+    assert!(::event_is_complete(&fill_event).unwrap());
+
+    // A non-blocking read is complete once `finish` returns:
+    let mut vec = vec![0u32; DATASET_SIZE];
+    unsafe { ::enqueue_read_buffer(&queue, &buffer, false, 0, &mut vec,
+        None::<::Event>, None::<&mut ::Event>).unwrap() };
+    ::finish(&queue).unwrap();
+    assert!(vec.iter().all(|&x| x == 17));
+}
 //
 // #[test]
 // fn fill_with_float4() {
@@ -97,3 +181,39 @@ fn fill() {
 //         assert_eq!(ele, final_val);
 //     }
 // }
+
+#[test]
+fn fill_event_list() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context_properties = ::ContextProperties::new().platform(platform_id);
+    let context = ::create_context(Some(&context_properties),
+        &[device], None, None).unwrap();
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+
+    let buffer_a = unsafe { ::create_buffer::<_, u32>(&context, ::MEM_READ_WRITE, DATASET_SIZE, None).unwrap() };
+    let buffer_b = unsafe { ::create_buffer::<_, u32>(&context, ::MEM_READ_WRITE, DATASET_SIZE, None).unwrap() };
+
+    let mut events = ::EventList::new();
+    for &(buffer, value) in &[(&buffer_a, 11u32), (&buffer_b, 22u32)] {
+        let mut event = ::Event::null();
+        ::enqueue_fill_buffer::<u32, _, _, _>(&queue, buffer, value, 0, DATASET_SIZE,
+            None::<::Event>, Some(&mut event), None).unwrap();
+        events.push(event);
+    }
+    // Null events are never added:
+    events.push(::Event::null());
+    assert_eq!(events.len(), 2);
+
+    let mut marker = ::Event::null();
+    ::enqueue_marker_with_wait_list(&queue, Some(&events), Some(&mut marker), None).unwrap();
+    events.wait().unwrap();
+    assert!(events.iter().all(|ev| ::event_is_complete(ev).unwrap()));
+    ::wait_for_event(&marker).unwrap();
+
+    let mut vec = vec![0u32; DATASET_SIZE];
+    unsafe { ::enqueue_read_buffer::<u32, _, _, _>(&queue, &buffer_b, true, 0, &mut vec,
+        None::<::Event>, None::<&mut ::Event>).unwrap() };
+    assert!(vec.iter().all(|x| *x == 22));
+}
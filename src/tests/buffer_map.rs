@@ -0,0 +1,97 @@
+const DATASET_SIZE: usize = 1 << 14;
+
+#[test]
+fn map_buffer_guard() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context_properties = ::ContextProperties::new().platform(platform_id);
+    let context = ::create_context(Some(&context_properties),
+        &[device], None, None).unwrap();
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+
+    let buffer = unsafe { ::create_buffer::<_, u32>(&context, ::MEM_READ_WRITE, DATASET_SIZE,
+        None).unwrap() };
+    ::enqueue_fill_buffer::<u32, _, _, _>(&queue, &buffer, 0, 0, DATASET_SIZE,
+        None::<::Event>, None::<&mut ::Event>, None).unwrap();
+    ::finish(&queue).unwrap();
+
+    {
+        let mut mapped = ::map_buffer::<u32>(&queue, &buffer, ::MAP_READ | ::MAP_WRITE,
+            0, DATASET_SIZE).unwrap();
+        assert_eq!(mapped.len(), DATASET_SIZE);
+        assert!(mapped.iter().all(|&x| x == 0));
+
+        for (i, x) in mapped.iter_mut().enumerate() {
+            *x = i as u32;
+        }
+    }
+
+    let mut vec = vec![0u32; DATASET_SIZE];
+    unsafe { ::enqueue_read_buffer(&queue, &buffer, true, 0, &mut vec,
+        None::<::Event>, None::<&mut ::Event>).unwrap() };
+
+    for (i, &x) in vec.iter().enumerate() {
+        assert_eq!(x, i as u32);
+    }
+}
+
+#[test]
+fn enqueue_map_buffer_guarded_async() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+
+    let mut vec = vec![7u32; DATASET_SIZE];
+    let buffer = unsafe { ::create_buffer(&context, ::MEM_READ_WRITE | ::MEM_COPY_HOST_PTR,
+        DATASET_SIZE, Some(&vec)).unwrap() };
+
+    {
+        let mut map_event = ::Event::null();
+        let mut mapped = unsafe {
+            ::enqueue_map_buffer_guarded::<u32, _, _>(&queue, &buffer, false, ::MAP_WRITE,
+                0, DATASET_SIZE, None::<::Event>, Some(&mut map_event)).unwrap()
+        };
+        ::wait_for_event(&map_event).unwrap();
+
+        mapped[0] = 42;
+        mapped[DATASET_SIZE - 1] = 43;
+    }
+
+    // The guard has finished unmapping by the time it is dropped:
+    unsafe { ::enqueue_read_buffer(&queue, &buffer, true, 0, &mut vec,
+        None::<::Event>, None::<&mut ::Event>).unwrap() };
+
+    assert_eq!(vec[0], 42);
+    assert_eq!(vec[DATASET_SIZE - 1], 43);
+    assert!(vec[1..DATASET_SIZE - 1].iter().all(|&x| x == 7));
+}
+
+#[test]
+fn map_flags_validate() {
+    use ::{MapFlags, ErrorKind};
+
+    MapFlags::new().read().write().validate().unwrap();
+    MapFlags::new().write_invalidate_region().validate().unwrap();
+
+    let invalid = MapFlags::new().read().write_invalidate_region();
+    match invalid.validate() {
+        Err(err) => match *err.kind() {
+            ErrorKind::Conversion(_) => (),
+            _ => panic!("Unexpected error kind: {}", err),
+        },
+        Ok(_) => panic!("{:?} should be rejected", invalid),
+    }
+
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+    let buffer = unsafe { ::create_buffer::<_, u32>(&context, ::MEM_READ_WRITE, DATASET_SIZE,
+        None).unwrap() };
+
+    assert!(::map_buffer::<u32>(&queue, &buffer, invalid, 0, DATASET_SIZE).is_err());
+}
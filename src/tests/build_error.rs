@@ -23,3 +23,141 @@ fn bad_kernel_variable_names() {
     ::create_build_program(&context, &[CString::new(kernel).unwrap()],
         None::<&[()]>, &CString::new("").unwrap()).unwrap();
 }
+
+#[test]
+fn build_with_timeout() {
+    use std::time::Duration;
+
+    let kernel = r#"
+        kernel void multiply(global float* buffer, float coeff) {
+            buffer[get_global_id(0)] *= coeff;
+        }
+    "#;
+
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+
+    let program = ::create_program_with_source(&context, &[CString::new(kernel).unwrap()])
+        .unwrap();
+    ::build_program_with_timeout(&program, Some(&[device]), &CString::new("").unwrap(),
+        Duration::from_secs(60)).unwrap();
+
+    ::create_kernel(&program, "multiply").unwrap();
+}
+
+#[test]
+fn build_with_timeout_error() {
+    use std::time::Duration;
+
+    let kernel = r#"
+        kernel void multiply(global float* buffer, float coeff) {
+            not_a_variable;
+        }
+    "#;
+
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+
+    let program = ::create_program_with_source(&context, &[CString::new(kernel).unwrap()])
+        .unwrap();
+    assert!(::build_program_with_timeout(&program, Some(&[device]),
+        &CString::new("").unwrap(), Duration::from_secs(60)).is_err());
+}
+
+#[test]
+fn build_async() {
+    use std::sync::mpsc;
+    use std::time::Duration;
+    use ::{ProgramBuildInfo, ProgramBuildInfoResult, ProgramBuildStatus};
+
+    let kernel = r#"
+        kernel void multiply(global float* buffer, float coeff) {
+            buffer[get_global_id(0)] *= coeff;
+        }
+    "#;
+
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+
+    let program = ::create_program_with_source(&context, &[CString::new(kernel).unwrap()])
+        .unwrap();
+    let (tx, rx) = mpsc::channel();
+
+    ::build_program_async(program, Some(&[device]), &CString::new("").unwrap(), move |program| {
+        tx.send(program).unwrap();
+    }).unwrap();
+
+    let program = rx.recv_timeout(Duration::from_secs(60)).unwrap();
+
+    match ::get_program_build_info(&program, device, ProgramBuildInfo::BuildStatus) {
+        ProgramBuildInfoResult::BuildStatus(status) => {
+            assert_eq!(status, ProgramBuildStatus::Success)
+        },
+        res => panic!("unexpected build info result: {}", res),
+    }
+
+    ::create_kernel(&program, "multiply").unwrap();
+}
+
+#[cfg(feature = "opencl_version_1_2")]
+#[test]
+fn build_after_unload_compiler() {
+    let kernel = r#"
+        kernel void multiply(global float* buffer, float coeff) {
+            buffer[get_global_id(0)] *= coeff;
+        }
+    "#;
+
+    let platform_id = ::default_platform().unwrap();
+    if platform_id.version().unwrap() < ::OpenclVersion::CL12 { return; }
+
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+
+    for _ in 0..2 {
+        let program = ::create_build_program(&context, &[CString::new(kernel).unwrap()],
+            Some(&[device]), &CString::new("").unwrap()).unwrap();
+        ::create_kernel(&program, "multiply").unwrap();
+        ::unload_platform_compiler(&platform_id, None).unwrap();
+    }
+}
+
+#[test]
+fn program_from_multiple_sources() {
+    let helpers = r#"
+        float scale(float x, float coeff) {
+            return x * coeff;
+        }
+    "#;
+
+    let kernels = r#"
+        kernel void multiply(global float* buffer, float coeff) {
+            buffer[get_global_id(0)] = scale(buffer[get_global_id(0)], coeff);
+        }
+    "#;
+
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+
+    let options = CString::new("").unwrap();
+
+    let program = ::create_program_with_sources(&context, &[helpers, kernels]).unwrap();
+    ::build_program(&program, Some(&[device][..]), &options, None, None).unwrap();
+    ::create_kernel(&program, "multiply").unwrap();
+
+    // The second source alone does not compile:
+    let program = ::create_program_with_sources(&context, &[kernels]).unwrap();
+    assert!(::build_program(&program, Some(&[device][..]), &options, None, None).is_err());
+
+    assert!(::create_program_with_sources(&context, &[]).is_err());
+    assert!(::create_program_with_sources(&context, &[helpers, ""]).is_err());
+}
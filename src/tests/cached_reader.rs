@@ -0,0 +1,44 @@
+
+const DATASET_SIZE: usize = 1 << 12;
+
+#[test]
+fn cached_reader() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+
+    let buffer = unsafe { ::create_buffer::<_, u32>(&context, ::MEM_READ_WRITE, DATASET_SIZE,
+        None).unwrap() };
+    ::enqueue_fill_buffer::<u32, _, _, _>(&queue, &buffer, 7, 0, DATASET_SIZE,
+        None::<::Event>, None::<&mut ::Event>, None).unwrap();
+    ::finish(&queue).unwrap();
+
+    let mut reader = ::CachedReader::<u32>::new(&queue, &buffer, 0, DATASET_SIZE);
+    assert_eq!(reader.transfer_count(), 0);
+
+    // Commands in other contexts (such as those of concurrently running
+    // tests) do not invalidate the cache:
+    assert!(reader.read().unwrap().iter().all(|&x| x == 7));
+    assert!(reader.read().unwrap().iter().all(|&x| x == 7));
+    assert_eq!(reader.transfer_count(), 1);
+
+    let data = vec![3u32; DATASET_SIZE];
+    unsafe { ::enqueue_write_buffer(&queue, &buffer, true, 0, &data,
+        None::<::Event>, None::<&mut ::Event>).unwrap() };
+
+    assert!(reader.read().unwrap().iter().all(|&x| x == 3));
+    assert_eq!(reader.transfer_count(), 2);
+
+    // A failed write does not invalidate the cache:
+    let too_long = vec![5u32; DATASET_SIZE * 2];
+    assert!(unsafe { ::enqueue_write_buffer(&queue, &buffer, true, 0, &too_long,
+        None::<::Event>, None::<&mut ::Event>) }.is_err());
+    reader.read().unwrap();
+    assert_eq!(reader.transfer_count(), 2);
+
+    reader.invalidate();
+    reader.read().unwrap();
+    assert_eq!(reader.transfer_count(), 3);
+}
@@ -0,0 +1,48 @@
+use std::mem;
+use std::f32;
+use ::ClHalf;
+
+#[test]
+fn cl_half_round_trip() {
+    // Values exactly representable in half precision:
+    let exact = [0.0f32, -0.0, 1.0, -1.0, 0.5, 2.0, 3.140625, 65504.0, -65504.0,
+        6.103515625e-5, 5.9604645e-8, 1024.0, 0.099975586];
+
+    for &val in exact.iter() {
+        let half = ClHalf::from_f32(val);
+        assert_eq!(half.to_f32(), val, "{}", val);
+        assert_eq!(half.to_f32().is_sign_negative(), val.is_sign_negative());
+    }
+
+    assert_eq!(ClHalf::from_f32(1.0).to_bits(), 0x3c00);
+    assert_eq!(ClHalf::from_f32(-2.0).to_bits(), 0xc000);
+    assert_eq!(ClHalf::from_f32(65504.0).to_bits(), 0x7bff);
+    assert_eq!(ClHalf::from_f32(5.9604645e-8).to_bits(), 0x0001);
+}
+
+#[test]
+fn cl_half_rounding() {
+    // 1.0 + 2^-11 is halfway between 1.0 and the next half, ties to even:
+    assert_eq!(ClHalf::from_f32(1.0 + 0.00048828125).to_bits(), 0x3c00);
+    assert_eq!(ClHalf::from_f32(1.0 + 0.00048828125 * 1.5).to_bits(), 0x3c01);
+    assert!((ClHalf::from_f32(0.1).to_f32() - 0.1).abs() < 0.0001);
+
+    // Overflow, underflow, and special values:
+    assert_eq!(ClHalf::from_f32(1.0e6).to_f32(), f32::INFINITY);
+    assert_eq!(ClHalf::from_f32(-1.0e6).to_f32(), f32::NEG_INFINITY);
+    assert_eq!(ClHalf::from_f32(1.0e-10).to_bits(), 0x0000);
+    assert!(ClHalf::from_f32(f32::NAN).to_f32().is_nan());
+}
+
+#[test]
+fn cl_half_buffer_size() {
+    assert_eq!(mem::size_of::<ClHalf>(), 2);
+
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let context = ::create_context_single(device_ids[0]).unwrap();
+
+    let buffer = unsafe { ::create_buffer::<_, ClHalf>(&context, ::MEM_READ_WRITE, 64, None)
+        .unwrap() };
+    assert_eq!(buffer.byte_size().unwrap(), 128);
+}
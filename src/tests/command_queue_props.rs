@@ -0,0 +1,96 @@
+use ::{CommandQueueProperties, CommandQueueInfo, CommandQueueInfoResult};
+
+#[test]
+fn command_queue_properties() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+
+    let props = CommandQueueProperties::new().profiling().out_of_order();
+    assert_eq!(props, CommandQueueProperties::from(props.bits()));
+    assert_eq!(CommandQueueProperties::from(0x3u64), props);
+
+    let queue = match ::create_command_queue(&context, &device, Some(props)) {
+        Ok(q) => q,
+        // Out-of-order execution is optional:
+        Err(_) => return,
+    };
+
+    match ::get_command_queue_info(&queue, CommandQueueInfo::Properties) {
+        CommandQueueInfoResult::Properties(p) => {
+            assert!(p.contains(::QUEUE_PROFILING_ENABLE));
+            assert!(p.contains(::QUEUE_OUT_OF_ORDER_EXEC_MODE_ENABLE));
+        },
+        r @ _ => panic!("{}", r),
+    }
+}
+
+#[test]
+fn command_queue_info_readback() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+    let queue = ::create_command_queue(&context, &device, Some(::QUEUE_PROFILING_ENABLE))
+        .unwrap();
+
+    match ::get_command_queue_info(&queue, CommandQueueInfo::Properties) {
+        CommandQueueInfoResult::Properties(p) => assert!(p.contains(::QUEUE_PROFILING_ENABLE)),
+        r @ _ => panic!("{}", r),
+    }
+
+    match ::get_command_queue_info(&queue, CommandQueueInfo::Context) {
+        CommandQueueInfoResult::Context(c) => assert_eq!(c, context),
+        r @ _ => panic!("{}", r),
+    }
+
+    match ::get_command_queue_info(&queue, CommandQueueInfo::Device) {
+        CommandQueueInfoResult::Device(d) => assert_eq!(d, device),
+        r @ _ => panic!("{}", r),
+    }
+
+    match ::get_command_queue_info(&queue, CommandQueueInfo::ReferenceCount) {
+        CommandQueueInfoResult::ReferenceCount(c) => assert!(c >= 1),
+        r @ _ => panic!("{}", r),
+    }
+
+    // Host queues have no size and pre-2.0 devices can't be asked:
+    match ::get_command_queue_info(&queue, CommandQueueInfo::Size) {
+        CommandQueueInfoResult::Error(_) => (),
+        r @ _ => panic!("{}", r),
+    }
+}
+
+#[test]
+#[cfg(feature = "opencl_version_2_1")]
+fn set_default_device_queue() {
+    use ::OpenclVersion;
+
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let version = device.version().unwrap();
+    if version < OpenclVersion::CL21 { return; }
+
+    let context = ::create_context_single(device).unwrap();
+
+    // Host queues are rejected:
+    let host_queue = ::create_command_queue(&context, &device, None).unwrap();
+    assert!(::set_default_device_command_queue(&context, &device, &host_queue, None).is_err());
+
+    let device_queue = match ::create_command_queue(&context, &device,
+            Some(CommandQueueProperties::new().on_device())) {
+        Ok(q) => q,
+        // Device-side enqueue is optional in OpenCL 3.0:
+        Err(_) if version >= OpenclVersion::CL30 => return,
+        Err(err) => panic!("{}", err),
+    };
+
+    match ::get_command_queue_info(&device_queue, CommandQueueInfo::Properties) {
+        CommandQueueInfoResult::Properties(p) => assert!(p.contains(::QUEUE_ON_DEVICE)),
+        r @ _ => panic!("{}", r),
+    }
+
+    ::set_default_device_command_queue(&context, &device, &device_queue, None).unwrap();
+}
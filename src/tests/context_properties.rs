@@ -0,0 +1,50 @@
+use libc::c_void;
+use ::{ContextProperties, ContextProperty, PlatformId, ErrorKind};
+
+fn fake_platform() -> PlatformId {
+    unsafe { PlatformId::from_raw(0x1000 as *mut c_void) }
+}
+
+#[test]
+fn context_properties_platform_only() {
+    let props = ContextProperties::new().platform(fake_platform());
+
+    assert_eq!(props.to_raw(), vec![ContextProperty::Platform as isize, 0x1000, 0]);
+    props.validate().unwrap();
+}
+
+#[test]
+fn context_properties_gl_sharing() {
+    let gl_ctx = 0x2000 as *mut c_void;
+    let glx_disp = 0x3000 as *mut c_void;
+
+    let props = ContextProperties::new()
+        .glx_display(glx_disp)
+        .gl_context(gl_ctx)
+        .platform(fake_platform());
+
+    assert!(props.contains_gl_context_or_sharegroup());
+    assert_eq!(props.to_raw(), vec![
+        ContextProperty::Platform as isize, 0x1000,
+        ContextProperty::GlContextKhr as isize, 0x2000,
+        ContextProperty::GlxDisplayKhr as isize, 0x3000,
+        0,
+    ]);
+    props.validate().unwrap();
+}
+
+#[test]
+fn context_properties_sharing_requires_platform() {
+    let props = ContextProperties::new().gl_context(0x2000 as *mut c_void);
+
+    match props.validate() {
+        Err(err) => match *err.kind() {
+            ErrorKind::Conversion(_) => (),
+            _ => panic!("Unexpected error kind: {}", err),
+        },
+        Ok(_) => panic!("GL sharing without a platform should be rejected"),
+    }
+
+    // A CGL sharegroup alone is valid:
+    ContextProperties::new().cgl_sharegroup(0x2000 as *mut c_void).validate().unwrap();
+}
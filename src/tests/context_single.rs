@@ -0,0 +1,171 @@
+use ::{ContextInfo, ContextInfoResult};
+
+#[test]
+fn create_context_single_device() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+
+    let context = ::create_context_single(device_ids[0]).unwrap();
+
+    match ::get_context_info(&context, ContextInfo::NumDevices) {
+        ContextInfoResult::NumDevices(n) => assert_eq!(n, 1),
+        r @ _ => panic!("{:?}", r),
+    }
+
+    assert_eq!(::get_context_platform(&context).unwrap(), Some(platform_id));
+}
+
+#[test]
+fn create_context_all_devices_with_callback() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let properties = ::ContextProperties::new().platform(platform_id);
+
+    let context = ::create_context_with_callback(Some(&properties), &device_ids,
+        Box::new(|_: &str, _: &[u8]| ())).unwrap();
+
+    match ::get_context_info(&context, ContextInfo::NumDevices) {
+        ContextInfoResult::NumDevices(n) => assert_eq!(n as usize, device_ids.len()),
+        r @ _ => panic!("{:?}", r),
+    }
+
+    let no_devices: [::DeviceId; 0] = [];
+    assert!(::create_context_with_callback(Some(&properties), &no_devices,
+        Box::new(|_: &str, _: &[u8]| ())).is_err());
+}
+
+fn context_ref_count(context: &::Context) -> u32 {
+    match ::get_context_info(context, ContextInfo::ReferenceCount) {
+        ContextInfoResult::ReferenceCount(c) => c,
+        r @ _ => panic!("{:?}", r),
+    }
+}
+
+#[test]
+fn clone_handles_ref_counts() {
+    use ::{CommandQueueInfo, CommandQueueInfoResult, ProgramInfo, ProgramInfoResult};
+    use std::ffi::CString;
+
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let context = ::create_context_single(device_ids[0]).unwrap();
+
+    let base = context_ref_count(&context);
+    {
+        let clone = context.clone();
+        assert_eq!(clone.as_ptr(), context.as_ptr());
+        assert_eq!(context_ref_count(&context), base + 1);
+    }
+    assert_eq!(context_ref_count(&context), base);
+
+    let queue = ::create_command_queue(&context, &device_ids[0], None).unwrap();
+    let queue_clone = queue.clone();
+    match ::get_command_queue_info(&queue, CommandQueueInfo::ReferenceCount) {
+        CommandQueueInfoResult::ReferenceCount(c) => assert_eq!(c, 2),
+        r @ _ => panic!("{:?}", r),
+    }
+    drop(queue_clone);
+
+    let src = "kernel void k(global int* a) { a[get_global_id(0)] = 0; }";
+    let program = ::create_build_program(&context, &[CString::new(src).unwrap()],
+        None::<&[::DeviceId]>, &CString::new("").unwrap()).unwrap();
+    let program_clone = program.clone();
+    match ::get_program_info(&program, ProgramInfo::ReferenceCount) {
+        ProgramInfoResult::ReferenceCount(c) => assert_eq!(c, 2),
+        r @ _ => panic!("{:?}", r),
+    }
+    drop(program_clone);
+
+    // The clone remains valid after the original (and its program) are dropped:
+    let kernel = ::create_kernel(&program, "k").unwrap();
+    let kernel_clone = kernel.clone();
+    drop(kernel);
+    drop(program);
+    assert_eq!(::functions::get_kernel_name(&kernel_clone), "k");
+}
+
+#[test]
+fn retain_release_context() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let context = ::create_context_single(device_ids[0]).unwrap();
+
+    let base = context_ref_count(&context);
+
+    unsafe { ::retain_context(&context).unwrap(); }
+    assert_eq!(context_ref_count(&context), base + 1);
+
+    unsafe { ::release_context(&context).unwrap(); }
+    assert_eq!(context_ref_count(&context), base);
+}
+
+#[test]
+fn context_callback_reports_errors() {
+    use std::sync::{Arc, Mutex};
+
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let properties = ::ContextProperties::new().platform(platform_id);
+
+    let reports = Arc::new(Mutex::new(Vec::<String>::new()));
+    let reports_cb = reports.clone();
+    let context = ::create_context_with_callback(Some(&properties), &device_ids[..1],
+        Box::new(move |errinfo: &str, _: &[u8]| {
+            reports_cb.lock().unwrap().push(errinfo.to_owned())
+        })).unwrap();
+    let context_clone = context.clone();
+
+    // Reading past the end of a buffer is an error the implementation
+    // reports through the context callback:
+    let queue = ::create_command_queue(&context, &device_ids[0], None).unwrap();
+    let buffer = unsafe { ::create_buffer::<_, u32>(&context, ::MEM_READ_WRITE, 64, None)
+        .unwrap() };
+    let mut vec = vec![0u32; 128];
+    assert!(unsafe { ::enqueue_read_buffer(&queue, &buffer, true, 0, &mut vec,
+        None::<::Event>, None::<&mut ::Event>) }.is_err());
+    ::finish(&queue).unwrap();
+    assert!(!reports.lock().unwrap().is_empty());
+
+    // The callback outlives the handles while the queue and buffer still
+    // refer to the context:
+    drop(context);
+    drop(context_clone);
+    assert_eq!(Arc::strong_count(&reports), 2);
+
+    drop(buffer);
+    drop(queue);
+
+    // ...and is dropped once the context is destroyed, where supported:
+    if cfg!(feature = "opencl_version_3_0")
+            && platform_id.version().unwrap() >= ::OpenclVersion::new(3, 0) {
+        for _ in 0..100 {
+            if Arc::strong_count(&reports) == 1 { break; }
+            ::std::thread::sleep(::std::time::Duration::from_millis(10));
+        }
+        assert_eq!(Arc::strong_count(&reports), 1);
+    }
+}
+
+#[test]
+fn context_info_devices_and_properties() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+
+    let context = ::create_context_single(device).unwrap();
+    match ::get_context_info(&context, ContextInfo::Devices) {
+        ContextInfoResult::Devices(ds) => assert_eq!(ds, vec![device]),
+        r @ _ => panic!("{:?}", r),
+    }
+    match ::get_context_info(&context, ContextInfo::Properties) {
+        ContextInfoResult::Properties(props) => assert_eq!(props.get_platform(), Some(platform_id)),
+        r @ _ => panic!("{:?}", r),
+    }
+
+    // Without properties, an empty set is reported rather than an error:
+    let context = ::create_context(None, &[device], None, None).unwrap();
+    match ::get_context_info(&context, ContextInfo::Properties) {
+        ContextInfoResult::Properties(props) => assert_eq!(props.get_platform(), None),
+        r @ _ => panic!("{:?}", r),
+    }
+}
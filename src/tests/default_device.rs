@@ -0,0 +1,25 @@
+use ::{DeviceInfo, DeviceInfoResult};
+
+#[test]
+fn default_platform_and_device() {
+    let platform = ::default_platform().unwrap();
+    let device = ::default_device(&platform).unwrap();
+
+    let has_gpu = !::get_device_ids(&platform, Some(::DEVICE_TYPE_GPU), None).unwrap()
+        .is_empty();
+
+    if has_gpu && ::std::env::var("OCL_DEFAULT_DEVICE_TYPE").is_err() {
+        match ::get_device_info(&device, DeviceInfo::Type) {
+            DeviceInfoResult::Type(t) => assert!(t.contains(::DEVICE_TYPE_GPU)),
+            r @ _ => panic!("{:?}", r),
+        }
+    }
+
+    // Selection is deterministic:
+    assert_eq!(::default_device(&platform).unwrap(), device);
+}
+
+#[test]
+fn default_platform_none_available() {
+    assert!(::functions::select_default_platform(&[]).is_err());
+}
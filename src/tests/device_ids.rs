@@ -0,0 +1,49 @@
+use ::{DeviceInfo, DeviceInfoResult};
+
+#[test]
+fn get_device_ids_gpu_cpu() {
+    let types = ::DEVICE_TYPE_GPU | ::DEVICE_TYPE_CPU;
+
+    for platform in ::get_platform_ids().unwrap() {
+        let all = ::get_device_ids(&platform, Some(::DEVICE_TYPE_ALL), None).unwrap();
+        let gpu_cpu = ::get_device_ids(&platform, Some(types), None).unwrap();
+        assert!(gpu_cpu.len() <= all.len());
+
+        for device in gpu_cpu.iter() {
+            match ::get_device_info(device, DeviceInfo::Type) {
+                DeviceInfoResult::Type(t) => assert!(types.intersects(t)),
+                r @ _ => panic!("{:?}", r),
+            }
+        }
+
+        // Platforms lacking a device type yield an empty list rather than an error:
+        let custom = ::get_device_ids(&platform, Some(::DEVICE_TYPE_CUSTOM), None).unwrap();
+        assert!(custom.len() <= all.len());
+    }
+}
+
+#[test]
+fn device_and_platform_names() {
+    use ::{PlatformInfo, PlatformInfoResult};
+
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+
+    for device in device_ids.iter() {
+        let name = match ::get_device_info(device, DeviceInfo::Name) {
+            DeviceInfoResult::Name(n) => n,
+            r @ _ => panic!("{:?}", r),
+        };
+        assert_eq!(device.name().unwrap(), name);
+    }
+
+    let platform_name = match ::get_platform_info(&platform_id, PlatformInfo::Name) {
+        PlatformInfoResult::Name(n) => n,
+        r @ _ => panic!("{:?}", r),
+    };
+    assert_eq!(platform_id.name().unwrap(), platform_name);
+
+    // Invalid ids return an error rather than panicking:
+    assert!(unsafe { ::PlatformId::null() }.name().is_err());
+    assert!(unsafe { ::DeviceId::null() }.name().is_err());
+}
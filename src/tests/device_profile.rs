@@ -0,0 +1,110 @@
+use ::{DeviceInfo, DeviceInfoResult, Profile};
+
+#[test]
+fn device_profile_full_no_workarounds() {
+    assert_eq!(Profile::from_info_str("FULL_PROFILE").unwrap(), Profile::Full);
+    assert_eq!(Profile::from_info_str("EMBEDDED_PROFILE").unwrap(), Profile::Embedded);
+    assert!(Profile::from_info_str("PARTIAL_PROFILE").is_err());
+
+    for platform in ::get_platform_ids().unwrap() {
+        for device in ::get_device_ids(&platform, None, None).unwrap() {
+            let profile_string = match ::get_device_info(device, DeviceInfo::Profile) {
+                DeviceInfoResult::Profile(p) => p,
+                DeviceInfoResult::Error(err) => panic!("{}", err),
+                _ => unreachable!(),
+            };
+
+            let profile = ::device_profile(device).unwrap();
+
+            if profile_string.contains("FULL_PROFILE") {
+                assert_eq!(profile, Profile::Full);
+                assert!(!profile.is_embedded());
+                assert!(::device_supports_int64(device).unwrap());
+            }
+        }
+    }
+}
+
+#[test]
+fn device_has_fp16() {
+    for platform in ::get_platform_ids().unwrap() {
+        for device in ::get_device_ids(&platform, None, None).unwrap() {
+            let exts = match ::get_device_info(device, DeviceInfo::Extensions) {
+                DeviceInfoResult::Extensions(e) => e,
+                DeviceInfoResult::Error(err) => panic!("{}", err),
+                _ => unreachable!(),
+            };
+
+            let has_fp16 = ::device_has_fp16(device).unwrap();
+            assert_eq!(has_fp16, exts.split_whitespace().any(|ext| ext == "cl_khr_fp16"));
+
+            match ::get_device_info(device, DeviceInfo::NativeVectorWidthHalf) {
+                DeviceInfoResult::NativeVectorWidthHalf(w) => if !has_fp16 { assert_eq!(w, 0) },
+                DeviceInfoResult::Error(err) => panic!("{}", err),
+                _ => unreachable!(),
+            }
+        }
+    }
+}
+
+#[test]
+fn device_info_all() {
+    let platform_id = ::default_platform().unwrap();
+    let device = ::default_device(&platform_id).unwrap();
+
+    let summary = ::get_device_info_all(&device).unwrap();
+
+    assert!(!summary.name.is_empty());
+    assert!(!summary.vendor.is_empty());
+    assert!(summary.max_compute_units > 0);
+    assert!(summary.max_work_group_size > 0);
+    assert!(!summary.max_work_item_sizes.is_empty());
+    assert!(summary.global_mem_size > 0);
+    assert_eq!(summary.version, device.version().unwrap());
+}
+
+#[test]
+fn device_fp_config() {
+    let platform_id = ::default_platform().unwrap();
+    let device = ::default_device(&platform_id).unwrap();
+
+    match ::get_device_info(&device, DeviceInfo::SingleFpConfig) {
+        // Required of all non-custom devices:
+        DeviceInfoResult::SingleFpConfig(cfg) => {
+            assert!(cfg.contains(::FP_ROUND_TO_NEAREST | ::FP_INF_NAN), "{:?}", cfg)
+        },
+        r @ _ => panic!("{:?}", r),
+    }
+
+    // Devices without double (`cl_khr_fp64`) or half (`cl_khr_fp16`) support
+    // report an empty config:
+    match ::get_device_info(&device, DeviceInfo::DoubleFpConfig) {
+        DeviceInfoResult::DoubleFpConfig(_) => (),
+        r @ _ => panic!("{:?}", r),
+    }
+
+    match ::get_device_info(&device, DeviceInfo::HalfFpConfig) {
+        DeviceInfoResult::HalfFpConfig(cfg) => {
+            if !::device_has_fp16(&device).unwrap() { assert!(cfg.is_empty()); }
+        },
+        r @ _ => panic!("{:?}", r),
+    }
+}
+
+#[test]
+fn device_svm_capabilities() {
+    use ::OpenclVersion;
+
+    let platform_id = ::default_platform().unwrap();
+    let device = ::get_device_ids(&platform_id, None, None).unwrap()[0];
+
+    let caps = ::get_device_svm_capabilities(device).unwrap();
+
+    let version = device.version().unwrap();
+    if version < OpenclVersion::CL20 {
+        assert!(caps.is_empty());
+    } else if version < OpenclVersion::CL30 {
+        // Coarse-grained buffer sharing is mandatory for 2.x (optional in 3.0):
+        assert!(caps.contains(::SVM_COARSE_GRAIN_BUFFER));
+    }
+}
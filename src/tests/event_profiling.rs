@@ -0,0 +1,148 @@
+use std::ffi::CString;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use ::{Event, ProfilingSink, Context, DeviceId, CommandQueue, Mem, Kernel};
+
+const DATASET_SIZE: usize = 1 << 16;
+
+// Returns a context and profiling queue on the first device of the default
+// platform, along with an `add` kernel whose arguments (`buffer` and an
+// addend of 1.0) are already set.
+fn add_kernel() -> (Context, DeviceId, CommandQueue, Mem, Kernel) {
+    let src = r#"
+        __kernel void add(__global float* buffer, float addend) {
+            buffer[get_global_id(0)] += addend;
+        }
+    "#;
+
+    let platform_id = ::default_platform().unwrap();
+    let device = ::get_device_ids(&platform_id, None, None).unwrap()[0];
+    let context = ::create_context_single(device).unwrap();
+
+    let program = ::create_program_with_source(&context, &[CString::new(src).unwrap()])
+        .unwrap();
+    ::build_program(&program, None::<&[()]>, &CString::new("").unwrap(), None, None).unwrap();
+    let queue = ::create_command_queue(&context, &device, Some(::QUEUE_PROFILING_ENABLE))
+        .unwrap();
+
+    let buffer = unsafe { ::create_buffer::<_, f32>(&context, ::MEM_READ_WRITE, DATASET_SIZE,
+        None).unwrap() };
+    let kernel = ::create_kernel(&program, "add").unwrap();
+    ::set_kernel_arg(&kernel, 0, ::KernelArg::Mem::<f32>(&buffer)).unwrap();
+    ::set_kernel_arg(&kernel, 1, ::KernelArg::Scalar(1.0f32)).unwrap();
+
+    (context, device, queue, buffer, kernel)
+}
+
+#[test]
+fn profiling_summary_kernel_events() {
+    let (_context, _device, queue, _buffer, kernel) = add_kernel();
+
+    let mut events = vec![Event::null(), Event::null(), Event::null()];
+
+    for event in events.iter_mut() {
+        unsafe {
+            ::enqueue_kernel(&queue, &kernel, 1, None, &[DATASET_SIZE, 1, 1], None,
+                None::<Event>, Some(event)).unwrap();
+        }
+    }
+
+    ::finish(&queue).unwrap();
+
+    let summary = ::profiling_summary(&events).unwrap();
+
+    assert_eq!(summary.count, 3);
+    assert!(summary.min <= summary.mean);
+    assert!(summary.mean <= summary.max);
+    assert!(summary.total >= summary.max);
+    assert!(summary.total <= summary.max * 3);
+}
+
+#[test]
+fn profiling_summary_no_events() {
+    assert!(::profiling_summary(&[]).is_err());
+}
+
+#[test]
+fn profiling_start_end() {
+    let (context, device, queue, buffer, kernel) = add_kernel();
+
+    let mut event = Event::null();
+    unsafe {
+        ::enqueue_kernel(&queue, &kernel, 1, None, &[DATASET_SIZE, 1, 1], None,
+            None::<Event>, Some(&mut event)).unwrap();
+    }
+    ::wait_for_event(&event).unwrap();
+
+    let start = ::get_event_profiling_info(&event, ::ProfilingInfo::Start).time().unwrap();
+    let end = ::get_event_profiling_info(&event, ::ProfilingInfo::End).time().unwrap();
+    assert!(end >= start);
+    assert_eq!(::profiling_duration_ns(&event).unwrap(), end - start);
+
+    // Without profiling enabled:
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+    let mut event = Event::null();
+    ::enqueue_fill_buffer::<f32, _, _, _>(&queue, &buffer, 0.0, 0, DATASET_SIZE,
+        None::<Event>, Some(&mut event), None).unwrap();
+    ::wait_for_event(&event).unwrap();
+    assert!(::profiling_duration_ns(&event).is_err());
+}
+
+struct LabelSink(Mutex<Vec<(String, u64)>>);
+
+impl ProfilingSink for LabelSink {
+    fn record(&self, kernel_name: &str, duration_ns: u64) {
+        self.0.lock().unwrap().push((kernel_name.to_owned(), duration_ns));
+    }
+}
+
+#[test]
+fn profiling_sink_receives_label() {
+    let (_context, _device, mut queue, _buffer, kernel) = add_kernel();
+
+    let sink = Arc::new(LabelSink(Mutex::new(Vec::new())));
+    ::set_profiling_sink(&mut queue, Some(sink.clone())).unwrap();
+
+    let event = unsafe { ::enqueue_kernel_named(&queue, &kernel, "forward_pass", 1, None,
+        &[DATASET_SIZE, 1, 1], None, None::<Event>).unwrap() };
+    ::wait_for_event(&event).unwrap();
+
+    // The callback may be run on another thread shortly after completion:
+    for _ in 0..100 {
+        if !sink.0.lock().unwrap().is_empty() { break; }
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    {
+        let records = sink.0.lock().unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].0, "forward_pass");
+        assert!(records[0].1 > 0);
+    }
+
+    ::set_profiling_sink(&mut queue, None).unwrap();
+}
+
+#[test]
+fn profiling_sink_released_with_queue() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+    let mut queue = ::create_command_queue(&context, &device, Some(::QUEUE_PROFILING_ENABLE))
+        .unwrap();
+
+    let sink = Arc::new(LabelSink(Mutex::new(Vec::new())));
+    ::set_profiling_sink(&mut queue, Some(sink.clone())).unwrap();
+    assert_eq!(Arc::strong_count(&sink), 2);
+
+    // Clones carry the sink:
+    let queue_clone = queue.clone();
+    assert!(queue_clone.profiling_sink().is_some());
+    assert_eq!(Arc::strong_count(&sink), 3);
+
+    drop(queue_clone);
+    drop(queue);
+    assert_eq!(Arc::strong_count(&sink), 1);
+}
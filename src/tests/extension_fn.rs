@@ -0,0 +1,25 @@
+use ::{PlatformInfo, PlatformInfoResult};
+
+#[test]
+fn extension_function_address() {
+    let platform_id = ::default_platform().unwrap();
+
+    // Requires OpenCL 1.2:
+    if platform_id.version().unwrap() < ::OpenclVersion::CL12 { return; }
+
+    assert!(unsafe { ::get_extension_function_address_for_platform(&platform_id,
+        "clSurelyNonexistentFunctionNameXYZ", None) }.is_err());
+
+    let extensions = match ::get_platform_info(&platform_id, PlatformInfo::Extensions) {
+        PlatformInfoResult::Extensions(exts) => exts,
+        PlatformInfoResult::Error(err) => panic!("{}", err),
+        _ => unreachable!(),
+    };
+
+    // Best effort, most platforms are loaded through an ICD:
+    if extensions.contains("cl_khr_icd") {
+        let fn_ptr = unsafe { ::get_extension_function_address_for_platform(&platform_id,
+            "clIcdGetPlatformIDsKHR", None).unwrap() };
+        assert!(!fn_ptr.is_null());
+    }
+}
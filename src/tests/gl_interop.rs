@@ -0,0 +1,112 @@
+//! Tests requiring an OpenGL context shared with OpenCL.
+//!
+//! Enable with the `gl_interop` feature. Creating a shared context is left to
+//! the windowing system so only the failure path is exercised when no
+//! shared context is current.
+
+#[cfg(feature = "gl_interop")]
+#[test]
+fn create_from_gl_buffer_no_shared_context() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let context_properties = ::ContextProperties::new().platform(platform_id);
+    let context = ::create_context(Some(&context_properties),
+        &[device_ids[0]], None, None).unwrap();
+
+    // Without GL sharing properties the context can not share buffers:
+    let res = unsafe { ::create_from_gl_buffer(&context, 1, ::MEM_READ_WRITE) };
+    assert!(res.is_err());
+}
+
+#[cfg(feature = "gl_interop")]
+#[test]
+fn acquire_release_gl_objects_no_shared_context() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context_properties = ::ContextProperties::new().platform(platform_id);
+    let context = ::create_context(Some(&context_properties),
+        &[device], None, None).unwrap();
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+
+    // A buffer not created from a GL object can not be acquired or released:
+    let buffer = unsafe { ::create_buffer::<_, f32>(&context, ::MEM_READ_WRITE, 64, None)
+        .unwrap() };
+    let buffers = [buffer];
+
+    assert!(::enqueue_acquire_gl_objects(&queue, &buffers, None::<::Event>,
+        None::<&mut ::Event>).is_err());
+    assert!(::enqueue_release_gl_objects(&queue, &buffers, None::<::Event>,
+        None::<&mut ::Event>).is_err());
+}
+
+#[cfg(feature = "gl_interop")]
+#[test]
+fn gl_context_info_no_gl_context() {
+    use ::{GlContextInfo, GlContextInfoResult};
+
+    let platform_id = ::default_platform().unwrap();
+
+    // A platform is required:
+    match ::get_gl_context_info_khr(&::ContextProperties::new(), GlContextInfo::CurrentDevice) {
+        GlContextInfoResult::Error(_) => (),
+        r @ _ => panic!("Unexpected result: {:?}", r),
+    }
+
+    // Without a current GL context there is no device to report, whether or
+    // not the platform supports the extension:
+    let context_properties = ::ContextProperties::new().platform(platform_id);
+    for &request in &[GlContextInfo::CurrentDevice, GlContextInfo::Devices] {
+        match ::get_gl_context_info_khr(&context_properties, request) {
+            GlContextInfoResult::Error(_) => (),
+            GlContextInfoResult::Devices(ds) => assert!(ds.is_empty()),
+            r @ _ => panic!("Unexpected result: {:?}", r),
+        }
+    }
+}
+
+#[cfg(feature = "gl_interop")]
+#[test]
+fn gl_object_info_non_gl_buffer() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let context_properties = ::ContextProperties::new().platform(platform_id);
+    let context = ::create_context(Some(&context_properties),
+        &[device_ids[0]], None, None).unwrap();
+    let buffer = unsafe { ::create_buffer::<_, f32>(&context, ::MEM_READ_WRITE, 64, None)
+        .unwrap() };
+
+    // A buffer not created from a GL object has no GL object or texture info:
+    assert!(::get_gl_object_info(&buffer).is_err());
+
+    match ::get_gl_texture_info(&buffer, ::GlTextureInfo::TextureTarget) {
+        ::GlTextureInfoResult::Error(_) => (),
+        other => panic!("Expected an error, found: {}", other),
+    }
+}
+
+#[cfg(feature = "gl_interop")]
+#[test]
+fn create_from_gl_renderbuffer_image_info() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let context_properties = ::ContextProperties::new().platform(platform_id);
+    let context = ::create_context(Some(&context_properties),
+        &[device_ids[0]], None, None).unwrap();
+
+    // Renderbuffers can only be shared through a GL sharing context. Skip the
+    // remainder when one is not available:
+    let image = match unsafe { ::create_from_gl_renderbuffer(&context, 1, ::MEM_READ_ONLY) } {
+        Ok(img) => img,
+        Err(_) => return,
+    };
+
+    match ::get_image_info(&image, ::ImageInfo::Width) {
+        ::ImageInfoResult::Width(w) => assert!(w > 0),
+        r @ _ => panic!("Unexpected result: {:?}", r),
+    }
+
+    let (object_type, name) = ::get_gl_object_info(&image).unwrap();
+    assert_eq!(object_type, ::ClGlObjectType::ClGlObjectRenderbuffer);
+    assert_eq!(name, 1);
+}
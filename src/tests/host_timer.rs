@@ -0,0 +1,41 @@
+#![cfg(feature = "opencl_version_2_1")]
+
+use ::{ErrorKind, OpenclVersion};
+
+#[test]
+fn host_timer_version_low() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+
+    // Pretend the device only supports OpenCL 1.2:
+    let stub_version = OpenclVersion::CL12;
+
+    let err = ::get_device_and_host_timer(&device, Some(&stub_version)).unwrap_err();
+    match *err.cause().unwrap().kind() {
+        ErrorKind::VersionLow { detected, required } => {
+            assert_eq!(detected, OpenclVersion::CL12);
+            assert_eq!(required, OpenclVersion::CL21);
+        },
+        _ => panic!("Unexpected error: {}", err),
+    }
+
+    let err = ::get_host_timer(&device, Some(&stub_version)).unwrap_err();
+    match *err.cause().unwrap().kind() {
+        ErrorKind::VersionLow { .. } => (),
+        _ => panic!("Unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn host_timer_monotonic() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+
+    if device.version().unwrap() < OpenclVersion::CL21 { return; }
+
+    let (_, host_ts_0) = ::get_device_and_host_timer(&device, None).unwrap();
+    let host_ts_1 = ::get_host_timer(&device, None).unwrap();
+    assert!(host_ts_1 >= host_ts_0);
+}
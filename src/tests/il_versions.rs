@@ -0,0 +1,32 @@
+use ::{DeviceInfoResult, OpenclVersion};
+
+#[test]
+fn il_versions_parse() {
+    let ils = DeviceInfoResult::IlVersion("SPIR-V_1.0 SPIR-V_1.2".to_owned()).as_il_versions()
+        .unwrap();
+    assert_eq!(ils, vec!["SPIR-V_1.0".to_owned(), "SPIR-V_1.2".to_owned()]);
+
+    let ils = DeviceInfoResult::IlVersion(String::new()).as_il_versions().unwrap();
+    assert!(ils.is_empty());
+
+    let ils = DeviceInfoResult::IlsWithVersion(vec![
+        ("SPIR-V".to_owned(), OpenclVersion::new(1, 0)),
+        ("SPIR-V".to_owned(), OpenclVersion::new(1, 2)),
+    ]);
+    assert_eq!(ils.to_string(), "SPIR-V_1.0 SPIR-V_1.2");
+    assert_eq!(ils.as_il_versions().unwrap(),
+        vec!["SPIR-V_1.0".to_owned(), "SPIR-V_1.2".to_owned()]);
+
+    assert!(DeviceInfoResult::Available(true).as_il_versions().is_err());
+}
+
+#[test]
+fn device_il_versions() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+
+    for device in device_ids.iter() {
+        let ils = ::get_device_il_versions(device).unwrap();
+        assert!(ils.iter().all(|il| !il.is_empty() && !il.contains(' ')));
+    }
+}
@@ -0,0 +1,102 @@
+use ::{ImageDescriptor, ImageFormat, ImageChannelOrder, ImageChannelDataType, MemObjectType};
+
+fn rgba8() -> ImageFormat {
+    ImageFormat::new(ImageChannelOrder::Rgba, ImageChannelDataType::UnsignedInt8)
+}
+
+#[test]
+fn create_image_2d_and_3d() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let context_properties = ::ContextProperties::new().platform(platform_id);
+    let context = ::create_context(Some(&context_properties),
+        &[device_ids[0]], None, None).unwrap();
+
+    // 2D:
+    let desc_2d = ImageDescriptor::builder(MemObjectType::Image2d).dims(32, 16, 1);
+    assert_eq!(desc_2d.host_data_bytes(4), 32 * 16 * 4);
+    let data_2d = vec![0u8; 32 * 16 * 4];
+    let image_2d = unsafe { ::create_image(&context, ::MEM_READ_WRITE | ::MEM_COPY_HOST_PTR,
+        &rgba8(), &desc_2d, Some(&data_2d), None).unwrap() };
+    assert_eq!(image_2d.mem_type().unwrap(), MemObjectType::Image2d);
+
+    // 3D:
+    let desc_3d = ImageDescriptor::builder(MemObjectType::Image3d).dims(8, 8, 4);
+    assert_eq!(desc_3d.host_data_bytes(4), 8 * 8 * 4 * 4);
+    let data_3d = vec![0u8; 8 * 8 * 4 * 4];
+    let image_3d = unsafe { ::create_image(&context, ::MEM_READ_WRITE | ::MEM_COPY_HOST_PTR,
+        &rgba8(), &desc_3d, Some(&data_3d), None).unwrap() };
+    assert_eq!(image_3d.mem_type().unwrap(), MemObjectType::Image3d);
+
+    // Too little host data:
+    let short_data = vec![0u8; 32 * 16 * 4 - 1];
+    assert!(unsafe { ::create_image(&context, ::MEM_READ_WRITE | ::MEM_COPY_HOST_PTR,
+        &rgba8(), &desc_2d, Some(&short_data), None).is_err() });
+}
+
+#[test]
+fn supported_image_formats_rgba_unorm_int8() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let context_properties = ::ContextProperties::new().platform(platform_id);
+    let context = ::create_context(Some(&context_properties),
+        &[device_ids[0]], None, None).unwrap();
+
+    let formats = ::get_supported_image_formats(&context, ::MEM_READ_ONLY,
+        MemObjectType::Image2d).unwrap();
+
+    // Devices without image support report no formats:
+    if formats.is_empty() { return; }
+
+    let rgba_unorm_int8 = ImageFormat::new(ImageChannelOrder::Rgba,
+        ImageChannelDataType::UnormInt8);
+    assert!(formats.iter().any(|f| f.as_ref().ok() == Some(&rgba_unorm_int8)));
+}
+
+#[test]
+fn image_info_dims_and_format() {
+    use ::{ImageInfo, ImageInfoResult};
+
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let context_properties = ::ContextProperties::new().platform(platform_id);
+    let context = ::create_context(Some(&context_properties),
+        &[device_ids[0]], None, None).unwrap();
+
+    let desc = ImageDescriptor::builder(MemObjectType::Image2d).dims(64, 32, 1);
+    let image = unsafe { ::create_image::<_, u8>(&context, ::MEM_READ_WRITE, &rgba8(), &desc,
+        None, None).unwrap() };
+
+    match ::get_image_info(&image, ImageInfo::Width) {
+        ImageInfoResult::Width(w) => assert_eq!(w, 64),
+        r @ _ => panic!("{:?}", r),
+    }
+    match ::get_image_info(&image, ImageInfo::Height) {
+        ImageInfoResult::Height(h) => assert_eq!(h, 32),
+        r @ _ => panic!("{:?}", r),
+    }
+    match ::get_image_info(&image, ImageInfo::ElementSize) {
+        ImageInfoResult::ElementSize(s) => assert_eq!(s, 4),
+        r @ _ => panic!("{:?}", r),
+    }
+    match ::get_image_info(&image, ImageInfo::Format) {
+        ImageInfoResult::Format(f) => assert_eq!(f.unwrap(), rgba8()),
+        r @ _ => panic!("{:?}", r),
+    }
+}
+
+#[test]
+fn image_format_pixel_sizes() {
+    let rgba_unorm_int8 = ImageFormat::new(ImageChannelOrder::Rgba,
+        ImageChannelDataType::UnormInt8);
+    assert_eq!(rgba_unorm_int8.channel_count(), 4);
+    assert_eq!(rgba_unorm_int8.bytes_per_pixel(), 4);
+
+    let r_float = ImageFormat::new(ImageChannelOrder::R, ImageChannelDataType::Float);
+    assert_eq!(r_float.channel_count(), 1);
+    assert_eq!(r_float.bytes_per_pixel(), 4);
+
+    assert_eq!(rgba_unorm_int8, rgba_unorm_int8.clone());
+    assert!(rgba_unorm_int8 != ImageFormat::new_rgba());
+    assert!(rgba_unorm_int8 != r_float);
+}
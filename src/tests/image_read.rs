@@ -0,0 +1,34 @@
+#[test]
+fn read_image_region_to_vec() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context_properties = ::ContextProperties::new().platform(platform_id);
+    let context = ::create_context(Some(&context_properties),
+        &[device], None, None).unwrap();
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+
+    let dims = [16usize, 8, 1];
+    let pixels: Vec<u8> = (0..(dims[0] * dims[1] * 4)).map(|i| (i % 251) as u8).collect();
+
+    let image_desc = ::ImageDescriptor::new(::MemObjectType::Image2d, dims[0], dims[1], 1,
+        0, 0, 0, None);
+    let image_format = ::ImageFormat::new(::ImageChannelOrder::Rgba,
+        ::ImageChannelDataType::UnsignedInt8);
+    let image = unsafe { ::create_image(&context, ::MEM_READ_WRITE | ::MEM_COPY_HOST_PTR,
+        &image_format, &image_desc, Some(&pixels), None).unwrap() };
+
+    let (origin, region) = ([2usize, 3, 0], [5usize, 4, 1]);
+    let vec: Vec<u8> = ::read_image_to_vec(&queue, &image, origin, region).unwrap();
+
+    assert_eq!(vec.len(), region[0] * region[1] * 4);
+
+    for y in 0..region[1] {
+        for x in 0..region[0] {
+            for c in 0..4 {
+                let src_idx = (((origin[1] + y) * dims[0]) + origin[0] + x) * 4 + c;
+                assert_eq!(vec[((y * region[0]) + x) * 4 + c], pixels[src_idx]);
+            }
+        }
+    }
+}
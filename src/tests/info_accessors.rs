@@ -0,0 +1,36 @@
+use ::{DeviceInfo, PlatformInfo};
+
+#[test]
+fn device_info_result_accessors() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+
+    let name = ::get_device_info(&device, DeviceInfo::Name);
+    assert!(name.as_string().is_some());
+    assert!(name.as_u64().is_none());
+    assert!(name.as_bool().is_none());
+
+    let global_mem_size = ::get_device_info(&device, DeviceInfo::GlobalMemSize);
+    assert!(global_mem_size.as_u64().unwrap() > 0);
+    assert!(global_mem_size.as_string().is_none());
+    assert!(global_mem_size.as_size().is_none());
+
+    let max_compute_units = ::get_device_info(&device, DeviceInfo::MaxComputeUnits);
+    assert!(max_compute_units.as_u64().unwrap() > 0);
+
+    let max_wg_size = ::get_device_info(&device, DeviceInfo::MaxWorkGroupSize);
+    assert!(max_wg_size.as_size().unwrap() > 0);
+
+    let available = ::get_device_info(&device, DeviceInfo::Available);
+    assert_eq!(available.as_bool(), Some(true));
+}
+
+#[test]
+fn platform_info_result_accessors() {
+    let platform_id = ::default_platform().unwrap();
+
+    let vendor = ::get_platform_info(&platform_id, PlatformInfo::Vendor);
+    assert!(vendor.as_string().is_some());
+    assert!(vendor.as_u64().is_none());
+}
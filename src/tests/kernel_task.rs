@@ -0,0 +1,65 @@
+use std::ffi::CString;
+use ::{Context, CommandQueue, Program};
+
+const SENTINEL: u32 = 0xDEADBEEF;
+
+// Builds `src` for, and creates a queue on, the first device of the default
+// platform.
+fn build_program(src: &str) -> (Context, CommandQueue, Program) {
+    let platform_id = ::default_platform().unwrap();
+    let device = ::get_device_ids(&platform_id, None, None).unwrap()[0];
+    let context = ::create_context_single(device).unwrap();
+    let program = ::create_build_program(&context, &[CString::new(src).unwrap()],
+        Some(&[device]), &CString::new("").unwrap()).unwrap();
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+    (context, queue, program)
+}
+
+#[test]
+fn enqueue_task_sentinel() {
+    let src = r#"
+        __kernel void write_sentinel(__global uint* buffer, uint sentinel) {
+            buffer[0] = sentinel;
+        }
+    "#;
+
+    let (context, queue, program) = build_program(src);
+
+    let buffer = unsafe { ::create_buffer::<_, u32>(&context, ::MEM_READ_WRITE, 4, None)
+        .unwrap() };
+    ::enqueue_fill_buffer::<u32, _, _, _>(&queue, &buffer, 0, 0, 4,
+        None::<::Event>, None::<&mut ::Event>, None).unwrap();
+
+    let kernel = ::create_kernel(&program, "write_sentinel").unwrap();
+    ::set_kernel_arg(&kernel, 0, ::KernelArg::Mem::<u32>(&buffer)).unwrap();
+    ::set_kernel_arg(&kernel, 1, ::KernelArg::Scalar(SENTINEL)).unwrap();
+
+    let mut event = ::Event::null();
+    unsafe { ::enqueue_task(&queue, &kernel, None::<::Event>, Some(&mut event),
+        Some("write_sentinel")).unwrap(); }
+    ::wait_for_event(&event).unwrap();
+
+    let mut vec = vec![0u32; 4];
+    unsafe { ::enqueue_read_buffer(&queue, &buffer, true, 0, &mut vec,
+        None::<::Event>, None::<&mut ::Event>).unwrap() };
+
+    assert_eq!(vec, [SENTINEL, 0, 0, 0]);
+}
+
+#[test]
+fn enqueue_kernel_error_names_kernel() {
+    let src = r#"
+        __kernel void my_kernel(__global uint* buffer) {
+            buffer[get_global_id(0)] = 1;
+        }
+    "#;
+
+    let (_context, queue, program) = build_program(src);
+    let kernel = ::create_kernel(&program, "my_kernel").unwrap();
+
+    // The kernel argument is never set:
+    let err = unsafe { ::enqueue_kernel(&queue, &kernel, 1, None, &[16, 1, 1], None,
+        None::<::Event>, None::<&mut ::Event>).err().expect("launch should fail") };
+
+    assert!(format!("{}", err).contains("clEnqueueNDRangeKernel(\"my_kernel\")"), "{}", err);
+}
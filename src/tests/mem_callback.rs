@@ -0,0 +1,32 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn mem_object_destructor_callback() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let context_properties = ::ContextProperties::new().platform(platform_id);
+    let context = ::create_context(Some(&context_properties),
+        &[device_ids[0]], None, None).unwrap();
+
+    let buffer = unsafe { ::create_buffer::<_, f32>(&context, ::MEM_READ_WRITE, 1024, None)
+        .unwrap() };
+
+    let destroyed = Arc::new(Mutex::new(false));
+    let destroyed_cb = destroyed.clone();
+
+    ::set_mem_object_destructor_callback(&buffer,
+        move || { *destroyed_cb.lock().unwrap() = true; }, None).unwrap();
+
+    assert!(!*destroyed.lock().unwrap());
+    drop(buffer);
+
+    // The callback may be invoked asynchronously:
+    for _ in 0..200 {
+        if *destroyed.lock().unwrap() { break; }
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    assert!(*destroyed.lock().unwrap());
+}
@@ -0,0 +1,148 @@
+use ::{MemFlags, ErrorKind};
+
+#[test]
+fn mem_flags_validate() {
+    let read_write_conflict = MemFlags::new().read_only().write_only();
+    let host_ptr_conflict = MemFlags::new().read_write().use_host_ptr().alloc_host_ptr();
+    let legal = MemFlags::new().read_only().copy_host_ptr().host_no_access();
+
+    for flags in &[read_write_conflict, host_ptr_conflict] {
+        match flags.validate() {
+            Err(err) => match *err.kind() {
+                ErrorKind::Conversion(_) => (),
+                _ => panic!("Unexpected error kind: {}", err),
+            },
+            Ok(_) => panic!("{:?} should be rejected", flags),
+        }
+    }
+
+    legal.validate().unwrap();
+
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let context = ::create_context_single(device_ids[0]).unwrap();
+
+    assert!(unsafe { ::create_buffer::<_, u32>(&context, read_write_conflict, 64, None) }
+        .is_err());
+    let data = vec![0u32; 64];
+    unsafe { ::create_buffer(&context, legal, 64, Some(&data)).unwrap() };
+}
+
+#[test]
+fn create_buffer_host_data_length() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let context = ::create_context_single(device_ids[0]).unwrap();
+    let flags = ::MEM_READ_WRITE | ::MEM_COPY_HOST_PTR;
+
+    let short_data = vec![0.0f32; 63];
+    match unsafe { ::create_buffer(&context, flags, 64, Some(&short_data)) } {
+        Err(err) => match *err.kind() {
+            ErrorKind::Conversion(_) => (),
+            _ => panic!("Unexpected error kind: {}", err),
+        },
+        Ok(_) => panic!("A too-short host slice should be rejected"),
+    }
+
+    assert!(unsafe { ::create_buffer::<_, f32>(&context, flags, 64, None) }.is_err());
+
+    let data = vec![1.0f32; 64];
+    let buffer = unsafe { ::create_buffer(&context, flags, 64, Some(&data)).unwrap() };
+    assert_eq!(buffer.size().unwrap(), 64 * 4);
+}
+
+#[test]
+fn host_access_flags_enforced() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+    let mut data = vec![0u32; 64];
+
+    let write_only = unsafe { ::create_buffer::<_, u32>(&context,
+        ::MEM_READ_WRITE | ::MEM_HOST_WRITE_ONLY, 64, None).unwrap() };
+    unsafe { ::enqueue_write_buffer(&queue, &write_only, true, 0, &data,
+        None::<::Event>, None::<&mut ::Event>).unwrap() };
+    let err = unsafe { ::enqueue_read_buffer(&queue, &write_only, true, 0, &mut data,
+        None::<::Event>, None::<&mut ::Event>).unwrap_err() };
+    assert!(format!("{}", err).contains("may not read from"), "{}", err);
+
+    let read_only = unsafe { ::create_buffer::<_, u32>(&context,
+        ::MEM_READ_WRITE | ::MEM_HOST_READ_ONLY, 64, None).unwrap() };
+    unsafe { ::enqueue_read_buffer(&queue, &read_only, true, 0, &mut data,
+        None::<::Event>, None::<&mut ::Event>).unwrap() };
+    let err = unsafe { ::enqueue_write_buffer(&queue, &read_only, true, 0, &data,
+        None::<::Event>, None::<&mut ::Event>).unwrap_err() };
+    assert!(format!("{}", err).contains("may not write to"), "{}", err);
+}
+
+#[test]
+fn create_buffer_from_slice() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+
+    let data: Vec<u32> = (0..256).collect();
+    let buffer = ::create_buffer_from_slice(&context, ::MEM_READ_ONLY, &data).unwrap();
+
+    let mut read = vec![0u32; data.len()];
+    unsafe { ::enqueue_read_buffer(&queue, &buffer, true, 0, &mut read, None::<::Event>,
+        None::<&mut ::Event>).unwrap() };
+    assert_eq!(read, data);
+
+    assert!(::create_buffer_from_slice(&context, ::MEM_USE_HOST_PTR, &data).is_err());
+    assert!(::create_buffer_from_slice::<_, u32>(&context, ::MEM_READ_WRITE, &[]).is_err());
+}
+
+#[test]
+fn create_host_buffer_zero_copy() {
+    use std::ffi::CString;
+
+    const LEN: usize = 1024;
+
+    let src = r#"
+        __kernel void square(__global uint* buffer) {
+            uint idx = get_global_id(0);
+            buffer[idx] *= buffer[idx];
+        }
+    "#;
+
+    let platform_id = ::default_platform().unwrap();
+
+    // Host and device memory coincide on CPU devices:
+    let device = match ::get_device_ids(&platform_id, Some(::DEVICE_TYPE_CPU), None) {
+        Ok(ref ds) if !ds.is_empty() => ds[0],
+        _ => return,
+    };
+
+    let context = ::create_context_single(device).unwrap();
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+
+    let buffer = ::create_host_buffer::<_, u32>(&context, ::MEM_READ_WRITE, LEN).unwrap();
+    assert!(::create_host_buffer::<_, u32>(&context, ::MEM_COPY_HOST_PTR, LEN).is_err());
+    assert!(::create_host_buffer::<_, u32>(&context, ::MEM_USE_HOST_PTR, LEN).is_err());
+
+    // Initialize through the mapped pointer; unmapped when `mapped` drops:
+    {
+        let mut mapped = ::map_buffer::<u32>(&queue, &buffer, ::MAP_WRITE, 0, LEN).unwrap();
+        for (i, x) in mapped.iter_mut().enumerate() {
+            *x = i as u32;
+        }
+    }
+
+    let program = ::create_build_program(&context, &[CString::new(src).unwrap()],
+        Some(&[device]), &CString::new("").unwrap()).unwrap();
+    let kernel = ::create_kernel(&program, "square").unwrap();
+    ::set_kernel_arg(&kernel, 0, ::KernelArg::Mem::<u32>(&buffer)).unwrap();
+    unsafe { ::enqueue_kernel(&queue, &kernel, 1, None, &[LEN, 1, 1], None,
+        None::<::Event>, None::<&mut ::Event>).unwrap() };
+    ::finish(&queue).unwrap();
+
+    let mapped = ::map_buffer::<u32>(&queue, &buffer, ::MAP_READ, 0, LEN).unwrap();
+    for (i, &x) in mapped.iter().enumerate() {
+        assert_eq!(x, (i * i) as u32);
+    }
+}
@@ -0,0 +1,36 @@
+use ::{MemInfo, MemInfoResult, MemObjectType};
+
+#[test]
+fn mem_object_info() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let context = ::create_context_single(device_ids[0]).unwrap();
+
+    let buffer = unsafe { ::create_buffer::<_, u8>(&context, ::MEM_READ_WRITE, 4096, None)
+        .unwrap() };
+
+    match ::get_mem_object_info(&buffer, MemInfo::Size) {
+        MemInfoResult::Size(size) => assert_eq!(size, 4096),
+        r @ _ => panic!("{:?}", r),
+    }
+
+    assert_eq!(buffer.size().unwrap(), 4096);
+    assert_eq!(buffer.offset().unwrap(), 0);
+    assert_eq!(buffer.mem_type().unwrap(), MemObjectType::Buffer);
+    assert!(buffer.flags().unwrap().contains(::MEM_READ_WRITE));
+}
+
+#[test]
+fn mem_len_and_byte_size() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let context = ::create_context_single(device_ids[0]).unwrap();
+
+    let buffer = unsafe { ::create_buffer::<_, f32>(&context, ::MEM_READ_WRITE, 256, None)
+        .unwrap() };
+
+    assert_eq!(buffer.byte_size().unwrap(), 1024);
+    assert_eq!(buffer.len::<f32>().unwrap(), 256);
+    assert_eq!(buffer.len::<u8>().unwrap(), 1024);
+    assert!(!buffer.is_empty().unwrap());
+}
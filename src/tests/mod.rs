@@ -21,6 +21,39 @@ pub mod buffer_fill;
 // pub mod concurrent;
 // pub mod kernel_arg_ptr;
 pub mod vector_types;
+pub mod user_event;
+pub mod platform_info;
+pub mod mem_callback;
+pub mod image_read;
+pub mod image_create;
+pub mod device_profile;
+pub mod buffer_map;
+pub mod gl_interop;
+pub mod context_single;
+pub mod status;
+pub mod extension_fn;
+pub mod program_build_info;
+pub mod opencl_version;
+pub mod event_profiling;
+pub mod host_timer;
+pub mod sampler;
+pub mod native_kernel;
+pub mod svm_memcpy;
+pub mod mem_info;
+pub mod info_accessors;
+pub mod il_versions;
+pub mod sub_devices;
+pub mod cached_reader;
+pub mod kernel_task;
+pub mod program_binary;
+pub mod command_queue_props;
+pub mod mem_flags;
+pub mod device_ids;
+pub mod work_dims;
+pub mod default_device;
+pub mod cl_half;
+pub mod context_properties;
+pub mod pipe;
 // pub mod context_props;
 
 use rand::{self, Rng};
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use ::Event;
+
+const DATASET_SIZE: usize = 1024;
+
+#[test]
+fn native_kernel_increments_counter() {
+    let platform_id = ::default_platform().unwrap();
+
+    // Native kernels are most commonly supported by CPU devices:
+    let device = match ::get_device_ids(&platform_id, Some(::DEVICE_TYPE_CPU), None) {
+        Ok(ref ds) if !ds.is_empty() => ds[0],
+        _ => return,
+    };
+
+    if !::device_exec_capabilities(device).unwrap().contains(::EXEC_NATIVE_KERNEL) { return; }
+
+    let context = ::create_context_single(device).unwrap();
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+    let buffer = unsafe { ::create_buffer::<_, u32>(&context, ::MEM_READ_WRITE, DATASET_SIZE,
+        None).unwrap() };
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let counter_nk = counter.clone();
+    let mut event = Event::null();
+
+    unsafe {
+        ::enqueue_native_kernel(&queue, move |mem_ptrs: &[*mut ::libc::c_void]| {
+            assert_eq!(mem_ptrs.len(), 1);
+            assert!(!mem_ptrs[0].is_null());
+            counter_nk.fetch_add(1, Ordering::SeqCst);
+        }, &[buffer], None::<Event>, Some(&mut event)).unwrap();
+    }
+
+    ::wait_for_event(&event).unwrap();
+    ::finish(&queue).unwrap();
+
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn native_kernel_unsupported_device() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+
+    for &device in device_ids.iter() {
+        let caps = ::device_exec_capabilities(device).unwrap();
+        assert!(caps.contains(::EXEC_KERNEL));
+
+        if caps.contains(::EXEC_NATIVE_KERNEL) { continue; }
+
+        let context = ::create_context_single(device).unwrap();
+        let queue = ::create_command_queue(&context, &device, None).unwrap();
+
+        let res = unsafe {
+            ::enqueue_native_kernel(&queue, |_: &[*mut ::libc::c_void]| {
+                panic!("Native kernel should never run.");
+            }, &[], None::<Event>, None::<&mut Event>)
+        };
+
+        let err = res.unwrap_err();
+        assert!(format!("{}", err).contains("does not support native kernels"));
+    }
+}
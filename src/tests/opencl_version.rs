@@ -0,0 +1,89 @@
+use ::{OpenclVersion, ClVersions};
+use error::{Result as OclResult, ErrorKind};
+
+#[test]
+fn opencl_version_at_least_below() {
+    let ver = OpenclVersion::new(1, 2);
+
+    assert!(ver.at_least(OpenclVersion::CL12));
+    assert!(!ver.below(OpenclVersion::CL12));
+
+    assert!(ver.at_least(OpenclVersion::CL11));
+    assert!(!ver.below(OpenclVersion::CL11));
+
+    assert!(!ver.at_least(OpenclVersion::CL20));
+    assert!(ver.below(OpenclVersion::CL20));
+
+    assert!(OpenclVersion::new(1, 10).at_least(OpenclVersion::CL12));
+    assert!(OpenclVersion::new(2, 0).at_least(OpenclVersion::CL20));
+    assert!(OpenclVersion::new(2, 0).below(OpenclVersion::CL21));
+}
+
+#[test]
+fn opencl_version_cl_version_packing() {
+    fn pack(major: u32, minor: u32, patch: u32) -> u32 {
+        (major << 22) | (minor << 12) | patch
+    }
+
+    let cl30 = pack(3, 0, 0);
+    assert_eq!(OpenclVersion::from_cl_version(cl30), OpenclVersion::CL30);
+    assert_eq!(OpenclVersion::CL30.to_cl_version(), cl30);
+    assert_eq!(OpenclVersion::from_cl_version(cl30).to_cl_version(), cl30);
+
+    let cl215 = pack(2, 1, 5);
+    let ver = OpenclVersion::from_cl_version(cl215);
+    assert_eq!(ver, OpenclVersion::CL21);
+    assert_eq!(OpenclVersion::cl_version_patch(cl215), 5);
+    assert_eq!(ver.to_cl_version() | OpenclVersion::cl_version_patch(cl215) as u32, cl215);
+
+    let max = pack(1023, 1023, 4095);
+    assert_eq!(OpenclVersion::from_cl_version(max), OpenclVersion::new(1023, 1023));
+    assert_eq!(OpenclVersion::cl_version_patch(max), 4095);
+}
+
+#[test]
+fn opencl_version_ordering_and_parsing() {
+    assert!(OpenclVersion::CL12 < OpenclVersion::CL20);
+    assert!(OpenclVersion::new(1, 10) > OpenclVersion::new(1, 2));
+    assert_eq!(OpenclVersion::CL20, OpenclVersion::new(2, 0));
+    assert!(!(OpenclVersion::CL20 < OpenclVersion::new(2, 0)));
+
+    assert_eq!("OpenCL 2.1 CUDA 10.0.0".parse::<OpenclVersion>().unwrap(), OpenclVersion::CL21);
+    assert_eq!("OpenCL 1.2 pocl 1.1 Release".parse::<OpenclVersion>().unwrap(),
+        OpenclVersion::CL12);
+    assert_eq!("2.1".parse::<OpenclVersion>().unwrap(), OpenclVersion::CL21);
+    assert_eq!("3.0 trailing vendor text".parse::<OpenclVersion>().unwrap(),
+        OpenclVersion::CL30);
+
+    assert!("OpenCL".parse::<OpenclVersion>().is_err());
+    assert!("two.one".parse::<OpenclVersion>().is_err());
+}
+
+// Reports a fixed device and platform version.
+#[derive(Clone, Copy)]
+struct StubDevice(OpenclVersion);
+
+impl ClVersions for StubDevice {
+    fn device_versions(&self) -> OclResult<Vec<OpenclVersion>> { Ok(vec![self.0]) }
+    fn platform_version(&self) -> OclResult<OpenclVersion> { Ok(self.0) }
+}
+
+#[test]
+fn require_version_low() {
+    let stub = StubDevice(OpenclVersion::CL12);
+
+    match ::require_device_version(stub, OpenclVersion::CL20) {
+        Err(err) => match *err.kind() {
+            ErrorKind::VersionLow { detected, required } => {
+                assert_eq!(detected, OpenclVersion::CL12);
+                assert_eq!(required, OpenclVersion::CL20);
+            },
+            _ => panic!("unexpected error kind: {}", err),
+        },
+        Ok(()) => panic!("1.2 device passed a 2.0 requirement"),
+    }
+
+    assert!(::require_platform_version(stub, OpenclVersion::CL20).is_err());
+    assert!(::require_device_version(stub, OpenclVersion::CL12).is_ok());
+    assert!(::require_platform_version(stub, OpenclVersion::CL11).is_ok());
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "opencl_version_2_0")]
+
+use ::{OpenclVersion, PipeInfo};
+
+#[test]
+fn create_pipe_info() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+
+    // Rejected on older devices:
+    let res = ::create_pipe(&context, ::MEM_READ_WRITE, 4, 16, Some(&[OpenclVersion::CL12]));
+    assert!(res.is_err());
+
+    let version = device.version().unwrap();
+    if version < OpenclVersion::CL20 { return; }
+
+    let pipe = match ::create_pipe(&context, ::MEM_READ_WRITE, 4, 16, None) {
+        Ok(p) => p,
+        // Pipes are optional in OpenCL 3.0:
+        Err(_) if version >= OpenclVersion::CL30 => return,
+        Err(err) => panic!("{}", err),
+    };
+
+    assert_eq!(::get_pipe_info(&pipe, PipeInfo::PacketSize).unwrap(), 4);
+    assert_eq!(::get_pipe_info(&pipe, PipeInfo::MaxPackets).unwrap(), 16);
+
+    let buffer = unsafe { ::create_buffer::<_, u32>(&context, ::MEM_READ_WRITE, 16, None)
+        .unwrap() };
+    assert!(::get_pipe_info(&buffer, PipeInfo::PacketSize).is_err());
+}
@@ -0,0 +1,68 @@
+#[cfg(feature = "opencl_version_2_1")]
+#[test]
+fn host_timer_resolution() {
+    use ::{PlatformInfo, PlatformInfoResult};
+
+    let platform_id = ::default_platform().unwrap();
+
+    match ::get_platform_info(&platform_id, PlatformInfo::HostTimerResolution) {
+        PlatformInfoResult::HostTimerResolution(res) => assert!(res > 0),
+        PlatformInfoResult::Error(err) => panic!("{}", err),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn platform_extensions_with_version() {
+    use ::{PlatformInfo, PlatformInfoResult, OpenclVersion};
+
+    let platform_id = ::default_platform().unwrap();
+    let platform_version = ::get_platform_info(&platform_id, PlatformInfo::Version)
+        .as_opencl_version().unwrap();
+
+    let result = ::get_platform_info(&platform_id, PlatformInfo::ExtensionsWithVersion);
+
+    if platform_version < OpenclVersion::new(3, 0) {
+        match result {
+            PlatformInfoResult::Error(_) => return,
+            r @ _ => panic!("Expected a version error, got: {}", r),
+        }
+    }
+
+    let exts = match result {
+        PlatformInfoResult::ExtensionsWithVersion(exts) => exts,
+        PlatformInfoResult::Error(err) => panic!("{}", err),
+        _ => unreachable!(),
+    };
+
+    let ext_string = match ::get_platform_info(&platform_id, PlatformInfo::Extensions) {
+        PlatformInfoResult::Extensions(e) => e,
+        r @ _ => panic!("{}", r),
+    };
+
+    let mut names: Vec<&str> = exts.iter().map(|&(ref name, _)| name.as_str()).collect();
+    let mut plain_names: Vec<&str> = ext_string.split_whitespace().collect();
+    names.sort();
+    plain_names.sort();
+    assert_eq!(names, plain_names);
+}
+
+#[test]
+fn platform_by_name() {
+    use ::{PlatformInfo, PlatformInfoResult};
+
+    let platform_id = ::default_platform().unwrap();
+    let name = match ::get_platform_info(&platform_id, PlatformInfo::Name) {
+        PlatformInfoResult::Name(n) => n,
+        r @ _ => panic!("{}", r),
+    };
+
+    // Match on a lowercase substring of a known platform's name:
+    let substr: String = name.trim().chars().take(4).collect::<String>().to_lowercase();
+    let found = ::get_platform_by_name(&substr).unwrap();
+    let found_name: String = ::get_platform_info(&found, PlatformInfo::Name).into();
+    assert!(found_name.to_lowercase().contains(&substr));
+
+    let err = ::get_platform_by_name("no such platform name").unwrap_err();
+    assert!(format!("{}", err).contains(name.as_str()), "{}", err);
+}
@@ -0,0 +1,57 @@
+use std::ffi::CString;
+use ::{Context, DeviceId, Program};
+
+const DATASET_SIZE: usize = 64;
+
+// Runs the `add` kernel of `program` (adding 2.0) over a buffer filled with
+// 1.0 and returns the result.
+fn run_add(context: &Context, device: DeviceId, program: &Program) -> Vec<f32> {
+    let queue = ::create_command_queue(context, &device, None).unwrap();
+
+    let buffer = unsafe { ::create_buffer::<_, f32>(context, ::MEM_READ_WRITE, DATASET_SIZE,
+        None).unwrap() };
+    ::enqueue_fill_buffer::<f32, _, _, _>(&queue, &buffer, 1.0, 0, DATASET_SIZE,
+        None::<::Event>, None::<&mut ::Event>, None).unwrap();
+
+    let kernel = ::create_kernel(program, "add").unwrap();
+    ::set_kernel_arg(&kernel, 0, ::KernelArg::Mem::<f32>(&buffer)).unwrap();
+    ::set_kernel_arg(&kernel, 1, ::KernelArg::Scalar(2.0f32)).unwrap();
+    unsafe { ::enqueue_kernel(&queue, &kernel, 1, None, &[DATASET_SIZE, 1, 1], None,
+        None::<::Event>, None::<&mut ::Event>).unwrap(); }
+
+    let mut vec = vec![0.0f32; DATASET_SIZE];
+    unsafe { ::enqueue_read_buffer(&queue, &buffer, true, 0, &mut vec,
+        None::<::Event>, None::<&mut ::Event>).unwrap() };
+    vec
+}
+
+#[test]
+fn program_with_binary_broadcast() {
+    let src = r#"
+        __kernel void add(__global float* buffer, float addend) {
+            buffer[get_global_id(0)] += addend;
+        }
+    "#;
+
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+
+    // Obtain a binary by building from source first:
+    let source_program = ::create_build_program(&context, &[CString::new(src).unwrap()],
+        Some(&[device]), &CString::new("").unwrap()).unwrap();
+    let binaries = ::get_program_binaries(&source_program).unwrap();
+    assert_eq!(binaries.len(), 1);
+    assert!(binaries[0].len() > 0);
+
+    let program = ::create_program_with_binary_broadcast(&context, &[device], &binaries[0])
+        .unwrap();
+    ::build_program(&program, Some(&[device]), &CString::new("").unwrap(), None, None)
+        .unwrap();
+
+    // The program built from the binary behaves as the one built from source:
+    let vec = run_add(&context, device, &program);
+    assert!(vec.iter().all(|&x| x == 3.0));
+    assert_eq!(vec, run_add(&context, device, &source_program));
+}
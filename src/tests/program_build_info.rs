@@ -0,0 +1,64 @@
+#[cfg(feature = "opencl_version_2_0")]
+#[test]
+fn program_global_variable_total_size() {
+    use std::ffi::CString;
+    use ::{ProgramBuildInfo, ProgramBuildInfoResult};
+
+    let src = r#"
+        global float accum[64];
+
+        kernel void add(global float* buffer) {
+            accum[get_global_id(0) % 64] += buffer[get_global_id(0)];
+        }
+    "#;
+
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+
+    if device.version().unwrap() < ::OpenclVersion::CL20 { return; }
+
+    let context = ::create_context_single(device).unwrap();
+    let program = ::create_build_program(&context, &[CString::new(src).unwrap()],
+        Some(&[device]), &CString::new("-cl-std=CL2.0").unwrap()).unwrap();
+
+    match ::get_program_build_info(&program, device, ProgramBuildInfo::GlobalVariableTotalSize) {
+        ProgramBuildInfoResult::GlobalVariableTotalSize(size) => assert!(size > 0),
+        r @ _ => panic!("{:?}", r),
+    }
+}
+
+#[test]
+fn program_build_status_and_log() {
+    use std::ffi::CString;
+    use ::{ProgramBuildInfo, ProgramBuildInfoResult, ProgramBuildStatus};
+
+    let src = r#"
+        kernel void add(global float* buffer) {
+            buffer[get_global_id(0)] += 1.0f;
+        }
+    "#;
+
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+    let program = ::create_build_program(&context, &[CString::new(src).unwrap()],
+        Some(&[device]), &CString::new("-cl-mad-enable").unwrap()).unwrap();
+
+    match ::get_program_build_info(&program, device, ProgramBuildInfo::BuildStatus) {
+        ProgramBuildInfoResult::BuildStatus(status) => {
+            assert_eq!(status, ProgramBuildStatus::Success)
+        },
+        r @ _ => panic!("{:?}", r),
+    }
+
+    match ::get_program_build_info(&program, device, ProgramBuildInfo::BuildOptions) {
+        ProgramBuildInfoResult::BuildOptions(opts) => assert!(opts.contains("-cl-mad-enable")),
+        r @ _ => panic!("{:?}", r),
+    }
+
+    // A successful build's log is either empty or informational only:
+    let log = ::get_program_build_log(&program, device).unwrap();
+    assert!(!log.contains('\0'));
+}
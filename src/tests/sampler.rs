@@ -0,0 +1,120 @@
+use ::{AddressingMode, FilterMode, SamplerInfo, SamplerInfoResult};
+
+#[test]
+fn sampler_info() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let context = ::create_context_single(device_ids[0]).unwrap();
+
+    let sampler = ::create_sampler(&context, true, AddressingMode::Repeat, FilterMode::Linear)
+        .unwrap();
+
+    match ::get_sampler_info(&sampler, SamplerInfo::ReferenceCount) {
+        SamplerInfoResult::ReferenceCount(rc) => assert!(rc > 0),
+        r @ _ => panic!("{:?}", r),
+    }
+
+    match ::get_sampler_info(&sampler, SamplerInfo::Context) {
+        SamplerInfoResult::Context(ctx) => assert_eq!(ctx, context),
+        r @ _ => panic!("{:?}", r),
+    }
+
+    match ::get_sampler_info(&sampler, SamplerInfo::NormalizedCoords) {
+        SamplerInfoResult::NormalizedCoords(nc) => assert!(nc),
+        r @ _ => panic!("{:?}", r),
+    }
+
+    match ::get_sampler_info(&sampler, SamplerInfo::AddressingMode) {
+        SamplerInfoResult::AddressingMode(am) => assert_eq!(am, AddressingMode::Repeat),
+        r @ _ => panic!("{:?}", r),
+    }
+
+    match ::get_sampler_info(&sampler, SamplerInfo::FilterMode) {
+        SamplerInfoResult::FilterMode(fm) => assert_eq!(fm, FilterMode::Linear),
+        r @ _ => panic!("{:?}", r),
+    }
+}
+
+#[cfg(feature = "opencl_version_2_0")]
+#[test]
+fn sampler_with_mipmap_lod_range() {
+    use ::{SamplerProperties, DeviceInfo, DeviceInfoResult, OpenclVersion};
+
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+
+    if device.version().unwrap() < OpenclVersion::CL20 { return; }
+
+    let context = ::create_context_single(device).unwrap();
+
+    let supports_mipmaps = match ::get_device_info(&device, DeviceInfo::Extensions) {
+        DeviceInfoResult::Extensions(exts) => exts.contains("cl_khr_mipmap_image"),
+        r @ _ => panic!("{:?}", r),
+    };
+
+    let properties = SamplerProperties::new()
+        .normalized_coords(true)
+        .addressing_mode(AddressingMode::ClampToEdge)
+        .filter_mode(FilterMode::Linear)
+        .mip_filter_mode(FilterMode::Linear)
+        .lod_range(0.5, 4.0);
+
+    let sampler = match ::create_sampler_with_properties(&context, &properties, None) {
+        Ok(s) => s,
+        Err(err) => {
+            assert!(!supports_mipmaps, "{}", err);
+            return;
+        },
+    };
+
+    assert!(supports_mipmaps);
+
+    match ::get_sampler_info(&sampler, SamplerInfo::LodMin) {
+        SamplerInfoResult::LodMin(lod) => assert_eq!(lod, 0.5),
+        r @ _ => panic!("{:?}", r),
+    }
+
+    match ::get_sampler_info(&sampler, SamplerInfo::LodMax) {
+        SamplerInfoResult::LodMax(lod) => assert_eq!(lod, 4.0),
+        r @ _ => panic!("{:?}", r),
+    }
+
+    match ::get_sampler_info(&sampler, SamplerInfo::MipFilterMode) {
+        SamplerInfoResult::MipFilterMode(fm) => assert_eq!(fm, FilterMode::Linear),
+        r @ _ => panic!("{:?}", r),
+    }
+}
+
+#[cfg(feature = "opencl_version_2_0")]
+#[test]
+fn sampler_with_properties() {
+    use ::SamplerProperties;
+
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let context = ::create_context_single(device_ids[0]).unwrap();
+
+    // Falls back to `clCreateSampler` on pre-2.0 devices:
+    let properties = SamplerProperties::new()
+        .normalized_coords(false)
+        .addressing_mode(AddressingMode::ClampToEdge)
+        .filter_mode(FilterMode::Nearest);
+
+    let sampler = ::create_sampler_with_properties(&context, &properties, None).unwrap();
+
+    match ::get_sampler_info(&sampler, SamplerInfo::NormalizedCoords) {
+        SamplerInfoResult::NormalizedCoords(nc) => assert!(!nc),
+        r @ _ => panic!("{:?}", r),
+    }
+
+    match ::get_sampler_info(&sampler, SamplerInfo::AddressingMode) {
+        SamplerInfoResult::AddressingMode(am) => assert_eq!(am, AddressingMode::ClampToEdge),
+        r @ _ => panic!("{:?}", r),
+    }
+
+    match ::get_sampler_info(&sampler, SamplerInfo::FilterMode) {
+        SamplerInfoResult::FilterMode(fm) => assert_eq!(fm, FilterMode::Nearest),
+        r @ _ => panic!("{:?}", r),
+    }
+}
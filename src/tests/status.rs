@@ -0,0 +1,154 @@
+use num::FromPrimitive;
+use ::Status;
+
+#[test]
+fn status_is_invalid_object() {
+    let invalid_objects = [
+        Status::CL_INVALID_COMMAND_QUEUE,
+        Status::CL_INVALID_CONTEXT,
+        Status::CL_INVALID_MEM_OBJECT,
+        Status::CL_INVALID_KERNEL,
+        Status::CL_INVALID_EVENT,
+        Status::CL_INVALID_DEVICE,
+        Status::CL_INVALID_PROGRAM,
+        Status::CL_INVALID_SAMPLER,
+    ];
+
+    for code in -1100..1 {
+        if let Some(status) = Status::from_i32(code) {
+            assert_eq!(status.is_invalid_object(), invalid_objects.contains(&status),
+                "{:?}", status);
+        }
+    }
+
+    assert!(!Status::CL_SUCCESS.is_invalid_object());
+    assert!(!Status::CL_INVALID_VALUE.is_invalid_object());
+    assert!(!Status::CL_INVALID_KERNEL_ARGS.is_invalid_object());
+}
+
+#[test]
+fn status_success_and_groupings() {
+    assert!(Status::CL_SUCCESS.is_success());
+    assert!(!Status::CL_SUCCESS.is_error());
+    assert!(Status::CL_INVALID_VALUE.is_error());
+    assert!(Status::CL_OUT_OF_RESOURCES.is_error());
+    assert!(!Status::CL_DEVICE_NOT_FOUND.is_success());
+
+    assert!(Status::CL_OUT_OF_HOST_MEMORY.is_out_of_memory());
+    assert!(Status::CL_OUT_OF_RESOURCES.is_out_of_memory());
+    assert!(Status::CL_MEM_OBJECT_ALLOCATION_FAILURE.is_out_of_memory());
+    assert!(!Status::CL_INVALID_BUFFER_SIZE.is_out_of_memory());
+    assert!(!Status::CL_SUCCESS.is_out_of_memory());
+
+    for code in -1100..1 {
+        if let Some(status) = Status::from_i32(code) {
+            assert_eq!(status.is_success(), code == 0, "{:?}", status);
+            if status.is_out_of_memory() { assert!(status.is_transient(), "{:?}", status); }
+        }
+    }
+}
+
+#[test]
+fn status_category() {
+    assert_eq!(Status::CL_SUCCESS.category(), "success");
+    assert_eq!(Status::CL_OUT_OF_HOST_MEMORY.category(), "memory");
+    assert_eq!(Status::CL_MEM_OBJECT_ALLOCATION_FAILURE.category(), "memory");
+    assert_eq!(Status::CL_INVALID_ARG_SIZE.category(), "argument");
+    assert_eq!(Status::CL_INVALID_VALUE.category(), "argument");
+    assert_eq!(Status::CL_INVALID_MEM_OBJECT.category(), "object");
+    assert_eq!(Status::CL_INVALID_PLATFORM.category(), "object");
+    assert_eq!(Status::CL_BUILD_PROGRAM_FAILURE.category(), "build");
+    assert_eq!(Status::CL_PROFILING_INFO_NOT_AVAILABLE.category(), "profiling");
+    assert_eq!(Status::CL_DEVICE_NOT_FOUND.category(), "other");
+
+    for code in -1100..1 {
+        if let Some(status) = Status::from_i32(code) {
+            if status.is_invalid_object() { assert_eq!(status.category(), "object"); }
+        }
+    }
+}
+
+#[test]
+fn error_is_transient() {
+    use ::Error;
+
+    let resource_err = Error::eval_errcode(Status::CL_OUT_OF_RESOURCES as i32, (),
+        "clEnqueueNDRangeKernel", "").unwrap_err();
+    let arg_err = Error::eval_errcode(Status::CL_INVALID_ARG_VALUE as i32, (),
+        "clSetKernelArg", "").unwrap_err();
+
+    assert!(Status::CL_OUT_OF_RESOURCES.is_transient());
+    assert!(!Status::CL_INVALID_ARG_VALUE.is_transient());
+    assert!(resource_err.is_transient());
+    assert!(!arg_err.is_transient());
+
+    // The status is found within a chain of causes:
+    let chained = resource_err.chain("::some_fn");
+    assert!(chained.is_transient());
+}
+
+#[test]
+fn with_retry_transient_only() {
+    use ::Error;
+
+    let mut calls = 0;
+    let res: ::Result<()> = ::with_retry(3, || {
+        calls += 1;
+        Error::eval_errcode(Status::CL_OUT_OF_RESOURCES as i32, (), "clFinish", "")
+    });
+    assert!(res.is_err());
+    assert_eq!(calls, 3);
+
+    let mut calls = 0;
+    let res: ::Result<()> = ::with_retry(3, || {
+        calls += 1;
+        Error::eval_errcode(Status::CL_INVALID_ARG_VALUE as i32, (), "clSetKernelArg", "")
+    });
+    assert!(res.is_err());
+    assert_eq!(calls, 1);
+
+    let mut calls = 0;
+    let res = ::with_retry(3, || {
+        calls += 1;
+        if calls < 2 {
+            Error::eval_errcode(Status::CL_OUT_OF_HOST_MEMORY as i32, 0, "clFinish", "")
+        } else {
+            Ok(calls)
+        }
+    });
+    assert_eq!(res.unwrap(), 2);
+}
+
+#[test]
+fn option_chain_err() {
+    use error::ChainErr;
+
+    let missing: ::Result<Status> = Status::from_i32(1).chain_err(|| "missing");
+    let err = missing.unwrap_err();
+    assert_eq!(format!("{}", err), "missing");
+    assert!(err.cause().is_none());
+
+    let present = Status::from_i32(-5).chain_err(|| "missing").unwrap();
+    assert_eq!(present, Status::CL_OUT_OF_RESOURCES);
+}
+
+#[test]
+fn error_with_fn_info() {
+    use std::io;
+
+    let io_err = io::Error::new(io::ErrorKind::NotFound, "kernel source not found");
+    let err = ::Error::from(io_err).with_fn_info("clCreateProgramWithSource", "kernels.cl");
+
+    let msg = format!("{}", err);
+    assert!(msg.starts_with("Error executing function: clCreateProgramWithSource(\"kernels.cl\")"),
+        "{}", msg);
+    assert!(msg.contains("kernel source not found"), "{}", msg);
+
+    match *err.cause().unwrap().kind() {
+        ::ErrorKind::Io(_) => (),
+        _ => panic!("Unexpected cause: {}", err.cause().unwrap()),
+    }
+
+    let err = ::Error::from("bad").with_fn_info("clFinish", "");
+    assert_eq!(format!("{}", err), "Error executing function: clFinish: bad");
+}
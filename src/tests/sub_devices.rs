@@ -0,0 +1,70 @@
+use ::{DeviceInfo, DeviceInfoResult, DevicePartition, OpenclVersion};
+
+#[test]
+fn sub_devices_max() {
+    let platform_id = ::default_platform().unwrap();
+
+    // CPU devices are the most commonly partitionable:
+    let device = match ::get_device_ids(&platform_id, Some(::DEVICE_TYPE_CPU), None) {
+        Ok(ref ds) if !ds.is_empty() => ds[0],
+        _ => return,
+    };
+
+    if device.version().unwrap() < OpenclVersion::CL12 { return; }
+
+    let max_sub_devices = match ::get_device_info(&device,
+            DeviceInfo::PartitionMaxSubDevices) {
+        DeviceInfoResult::PartitionMaxSubDevices(max) => max,
+        r @ _ => panic!("{:?}", r),
+    };
+
+    // Non-partitionable devices report zero:
+    if max_sub_devices == 0 { return; }
+    assert!(max_sub_devices >= 1);
+
+    let over = DevicePartition::ByCounts(vec![1; max_sub_devices as usize + 1]);
+    let err = ::create_sub_devices(&device, &over, None).unwrap_err();
+    assert!(format!("{}", err).contains("exceeds the maximum"));
+
+    let sub_devices = ::create_sub_devices(&device, &DevicePartition::ByCounts(vec![1]), None)
+        .unwrap();
+    assert_eq!(sub_devices.len(), 1);
+
+    for sub_device in sub_devices.iter() {
+        unsafe { ::release_device(sub_device, None).unwrap(); }
+    }
+}
+
+#[test]
+fn sub_devices_partition_info() {
+    use ::DevicePartitionProperty;
+
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+
+    for device in device_ids.iter() {
+        if device.version().unwrap() < OpenclVersion::CL12 { continue; }
+
+        let max_sub_devices = match ::get_device_info(device,
+                DeviceInfo::PartitionMaxSubDevices) {
+            DeviceInfoResult::PartitionMaxSubDevices(max) => max,
+            r @ _ => panic!("{:?}", r),
+        };
+
+        let props = match ::get_device_info(device, DeviceInfo::PartitionProperties) {
+            DeviceInfoResult::PartitionProperties(props) => props,
+            r @ _ => panic!("{:?}", r),
+        };
+
+        let domains = match ::get_device_info(device, DeviceInfo::PartitionAffinityDomain) {
+            DeviceInfoResult::PartitionAffinityDomain(domains) => domains,
+            r @ _ => panic!("{:?}", r),
+        };
+
+        if max_sub_devices == 0 { assert!(props.is_empty()); }
+
+        if !props.contains(&DevicePartitionProperty::ByAffinityDomain) {
+            assert!(domains.is_empty());
+        }
+    }
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "opencl_version_2_0")]
+
+use std::mem;
+use libc::c_void;
+use ffi;
+use ::{Event, OpenclVersion};
+
+const LEN: usize = 256;
+
+#[test]
+fn svm_memcpy_overlap() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+
+    let mut data = vec![0u32; LEN];
+    let size = (LEN / 2) * mem::size_of::<u32>();
+    let base = data.as_mut_ptr();
+
+    // Overlapping ranges are rejected before anything is enqueued, regardless
+    // of device version:
+    let res = unsafe { ::enqueue_svm_memcpy(&queue, true, base.offset(LEN as isize / 4)
+        as *mut c_void, base as *const c_void, size, None::<Event>, None::<&mut Event>,
+        Some(&OpenclVersion::CL20)) };
+    assert!(format!("{}", res.unwrap_err()).contains("overlap"));
+
+    if device.version().unwrap() < OpenclVersion::CL20 { return; }
+
+    // Non-overlapping halves of a coarse-grained SVM allocation:
+    let svm_ptr = unsafe { ffi::clSVMAlloc(context.as_ptr(), ffi::CL_MEM_READ_WRITE,
+        LEN * mem::size_of::<u32>(), 0) } as *mut u32;
+    assert!(!svm_ptr.is_null());
+
+    unsafe {
+        ::enqueue_svm_memcpy(&queue, true, svm_ptr as *mut c_void, data.as_ptr() as *const c_void,
+            size, None::<Event>, None::<&mut Event>, None).unwrap();
+        ::enqueue_svm_memcpy(&queue, true, svm_ptr.offset(LEN as isize / 2) as *mut c_void,
+            svm_ptr as *const c_void, size, None::<Event>, None::<&mut Event>, None).unwrap();
+        ffi::clSVMFree(context.as_ptr(), svm_ptr as *mut c_void);
+    }
+}
+
+#[test]
+fn svm_memcpy_map_unmap() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    if device.version().unwrap() < OpenclVersion::CL20 { return; }
+
+    let context = ::create_context_single(device).unwrap();
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+
+    let data: Vec<u32> = (0..LEN as u32).collect();
+    let size = LEN * mem::size_of::<u32>();
+    let svm_ptr = unsafe { ffi::clSVMAlloc(context.as_ptr(), ffi::CL_MEM_READ_WRITE, size, 0) }
+        as *mut u32;
+    assert!(!svm_ptr.is_null());
+
+    unsafe {
+        ::enqueue_svm_memcpy(&queue, true, svm_ptr as *mut c_void, data.as_ptr() as *const c_void,
+            size, None::<Event>, None::<&mut Event>, None).unwrap();
+
+        // Coarse-grained regions must be mapped before host access:
+        ::enqueue_svm_map(&queue, true, ::MAP_READ, svm_ptr as *mut c_void, size,
+            None::<Event>, None::<&mut Event>, None).unwrap();
+        let read = ::std::slice::from_raw_parts(svm_ptr as *const u32, LEN);
+        assert_eq!(read, &data[..]);
+
+        let mut unmap_event = Event::null();
+        ::enqueue_svm_unmap(&queue, svm_ptr as *mut c_void, None::<Event>,
+            Some(&mut unmap_event), None).unwrap();
+        ::wait_for_event(&unmap_event).unwrap();
+
+        ffi::clSVMFree(context.as_ptr(), svm_ptr as *mut c_void);
+    }
+}
+
+#[test]
+fn svm_mem_fill_and_free() {
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+    let size = LEN * mem::size_of::<u32>();
+
+    // Invalid patterns are rejected regardless of device version:
+    let mut dummy = [0u8; 24];
+    let dummy_ptr = dummy.as_mut_ptr() as *mut c_void;
+    for &(pattern, size) in &[(&[0u8; 3][..], 24), (&[0u8; 16][..], 24), (&[][..], 24)] {
+        assert!(unsafe { ::enqueue_svm_mem_fill(&queue, dummy_ptr, pattern, size,
+            None::<Event>, None::<&mut Event>, Some(&OpenclVersion::CL20)) }.is_err());
+    }
+
+    if device.version().unwrap() < OpenclVersion::CL20 { return; }
+
+    let svm_ptr = unsafe { ffi::clSVMAlloc(context.as_ptr(), ffi::CL_MEM_READ_WRITE, size, 0) };
+    assert!(!svm_ptr.is_null());
+
+    let pattern: [u8; 4] = unsafe { mem::transmute(0xDEADBEEFu32) };
+    let mut read = vec![0u32; LEN];
+    let (tx, rx) = mpsc::channel();
+    let mut free_event = Event::null();
+
+    unsafe {
+        ::enqueue_svm_mem_fill(&queue, svm_ptr, &pattern, size, None::<Event>,
+            None::<&mut Event>, None).unwrap();
+        ::enqueue_svm_memcpy(&queue, true, read.as_mut_ptr() as *mut c_void, svm_ptr,
+            size, None::<Event>, None::<&mut Event>, None).unwrap();
+        ::enqueue_svm_free(&queue, &[svm_ptr], Some(move || tx.send(()).unwrap()),
+            None::<Event>, Some(&mut free_event), None).unwrap();
+    }
+
+    ::wait_for_event(&free_event).unwrap();
+    rx.recv_timeout(Duration::from_secs(10)).unwrap();
+    assert!(read.iter().all(|&x| x == 0xDEADBEEF));
+}
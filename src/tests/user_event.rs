@@ -0,0 +1,142 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use ::{CommandExecutionStatus, Event};
+
+#[test]
+fn user_event_wait_list() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context_properties = ::ContextProperties::new().platform(platform_id);
+    let context = ::create_context(Some(&context_properties),
+        &[device], None, None).unwrap();
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+
+    let user_event = ::create_user_event(&context).unwrap();
+    let mut marker = Event::null();
+
+    ::enqueue_marker_with_wait_list(&queue, Some(&user_event), Some(&mut marker), None)
+        .unwrap();
+    ::flush(&queue).unwrap();
+
+    assert!(!::event_is_complete(&user_event).unwrap());
+
+    ::set_user_event_status(&user_event, CommandExecutionStatus::Complete).unwrap();
+    ::wait_for_event(&marker).unwrap();
+
+    assert!(::event_is_complete(&marker).unwrap());
+    ::finish(&queue).unwrap();
+}
+
+#[test]
+fn user_event_callback_closure() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let context_properties = ::ContextProperties::new().platform(platform_id);
+    let context = ::create_context(Some(&context_properties),
+        &[device_ids[0]], None, None).unwrap();
+
+    let user_event = ::create_user_event(&context).unwrap();
+    let fired = Arc::new(Mutex::new(None));
+    let fired_cb = fired.clone();
+
+    ::set_event_callback_closure(&user_event, CommandExecutionStatus::Complete,
+        move |_event, status| { *fired_cb.lock().unwrap() = Some(status); }).unwrap();
+
+    assert!(fired.lock().unwrap().is_none());
+    ::set_user_event_status(&user_event, CommandExecutionStatus::Complete).unwrap();
+
+    // The callback may be invoked asynchronously:
+    for _ in 0..200 {
+        if fired.lock().unwrap().is_some() { break; }
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    assert_eq!(*fired.lock().unwrap(), Some(CommandExecutionStatus::Complete as i32));
+}
+
+#[test]
+fn wait_for_chained_events() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+    let context = ::create_context_single(device).unwrap();
+    let queue = ::create_command_queue(&context, &device,
+        Some(::QUEUE_OUT_OF_ORDER_EXEC_MODE_ENABLE)).unwrap();
+
+    let buffer = unsafe { ::create_buffer::<_, u32>(&context, ::MEM_READ_WRITE, 1024, None)
+        .unwrap() };
+
+    let mut fill_event = Event::null();
+    ::enqueue_fill_buffer::<u32, _, _, _>(&queue, &buffer, 5, 0, 1024,
+        None::<Event>, Some(&mut fill_event), None).unwrap();
+
+    let mut vec = vec![0u32; 1024];
+    let mut read_event = Event::null();
+    unsafe { ::enqueue_read_buffer(&queue, &buffer, false, 0, &mut vec,
+        Some(&fill_event), Some(&mut read_event)).unwrap() };
+
+    let events = [fill_event, read_event];
+    ::wait_for_events(events.len() as u32, &&events[..]).unwrap();
+    assert!(events.iter().all(|ev| ::event_is_complete(ev).unwrap()));
+    assert!(vec.iter().all(|&x| x == 5));
+
+    // An empty list is a no-op:
+    let no_events: [Event; 0] = [];
+    ::wait_for_events(0, &&no_events[..]).unwrap();
+}
+
+#[test]
+fn user_event_status() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let context = ::create_context_single(device_ids[0]).unwrap();
+
+    let user_event = ::create_user_event(&context).unwrap();
+    match ::get_event_status(&user_event).unwrap() {
+        ::EventStatus::Submitted | ::EventStatus::Queued => (),
+        s @ _ => panic!("Unexpected initial user event status: {:?}", s),
+    }
+    assert!(!::event_is_complete(&user_event).unwrap());
+
+    ::set_user_event_status(&user_event, CommandExecutionStatus::Complete).unwrap();
+    assert_eq!(::get_event_status(&user_event).unwrap(), ::EventStatus::Complete);
+    assert_eq!(::event_status(&user_event).unwrap(), CommandExecutionStatus::Complete);
+    assert!(::event_is_complete(&user_event).unwrap());
+
+    // A negative status is reported as an error code rather than a failure:
+    let aborted = ::create_user_event(&context).unwrap();
+    unsafe { ::ffi::clSetUserEventStatus(*aborted.as_ptr_ref(), -1000); }
+    assert_eq!(::get_event_status(&aborted).unwrap(), ::EventStatus::Error(-1000));
+    assert!(!::event_is_complete(&aborted).unwrap());
+    assert!(::event_status(&aborted).is_err());
+}
+
+#[test]
+fn wait_for_user_event_timeout() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let context = ::create_context_single(device_ids[0]).unwrap();
+
+    let complete_after = |delay_ms: u64| {
+        let user_event = ::create_user_event(&context).unwrap();
+        let user_event_thread = user_event.clone();
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(delay_ms));
+            ::set_user_event_status(&user_event_thread, CommandExecutionStatus::Complete)
+                .unwrap();
+        });
+        (user_event, handle)
+    };
+
+    let (user_event, handle) = complete_after(50);
+    assert!(::wait_for_event_timeout(&user_event, Duration::from_millis(200)).unwrap());
+    handle.join().unwrap();
+
+    let (user_event, handle) = complete_after(50);
+    assert!(!::wait_for_event_timeout(&user_event, Duration::from_millis(10)).unwrap());
+    // The callback left behind still fires (and is freed) on completion:
+    handle.join().unwrap();
+    ::wait_for_event(&user_event).unwrap();
+}
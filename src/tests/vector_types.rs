@@ -329,3 +329,28 @@ fn test_vector_types() {
         add_char16(context, &queue);
     }
 }
+
+#[test]
+fn vector_type_sizes() {
+    use std::mem::size_of;
+    use ::{Float2, Float3, Float4, Float16, Int4, Uint8, Uchar4, Double3};
+
+    assert_eq!(size_of::<Float2>(), 8);
+    assert_eq!(size_of::<Float3>(), 16);
+    assert_eq!(size_of::<Float4>(), 16);
+    assert_eq!(size_of::<Float16>(), 64);
+    assert_eq!(size_of::<Int4>(), 16);
+    assert_eq!(size_of::<Uint8>(), 32);
+    assert_eq!(size_of::<Uchar4>(), 4);
+    assert_eq!(size_of::<Double3>(), 32);
+
+    let v = Float4::from([1.0, 2.0, 3.0, 4.0f32]);
+    assert_eq!((v.s0(), v.s1(), v.s2(), v.s3()), (1.0, 2.0, 3.0, 4.0));
+    let a: [f32; 4] = v.into();
+    assert_eq!(a, [1.0, 2.0, 3.0, 4.0]);
+
+    let v3 = Float3::from([5.0, 6.0, 7.0f32]);
+    assert_eq!(v3.s2(), 7.0);
+    let a3: [f32; 3] = v3.into();
+    assert_eq!(a3, [5.0, 6.0, 7.0]);
+}
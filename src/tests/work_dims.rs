@@ -0,0 +1,210 @@
+use std::ffi::CString;
+use ::{DeviceInfo, DeviceInfoResult, DeviceId, Context, Program};
+
+// Builds `src` for the first device of the default platform.
+fn build_program(src: &str) -> (DeviceId, Context, Program) {
+    let platform_id = ::default_platform().unwrap();
+    let device = ::get_device_ids(&platform_id, None, None).unwrap()[0];
+    let context = ::create_context_single(device).unwrap();
+    let program = ::create_build_program(&context, &[CString::new(src).unwrap()],
+        Some(&[device]), &CString::new("").unwrap()).unwrap();
+    (device, context, program)
+}
+
+fn assert_err_contains(result: ::Result<()>, limit: &str) {
+    match result {
+        Err(err) => assert!(format!("{}", err).contains(limit), "{}", err),
+        Ok(_) => panic!("Expected a '{}' violation.", limit),
+    }
+}
+
+#[test]
+fn validate_work_dims() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+    let device = device_ids[0];
+
+    let max_dims = match ::get_device_info(device, DeviceInfo::MaxWorkItemDimensions) {
+        DeviceInfoResult::MaxWorkItemDimensions(d) => d as usize,
+        r @ _ => panic!("{:?}", r),
+    };
+    let max_sizes = match ::get_device_info(device, DeviceInfo::MaxWorkItemSizes) {
+        DeviceInfoResult::MaxWorkItemSizes(s) => s,
+        r @ _ => panic!("{:?}", r),
+    };
+    let max_group_size = match ::get_device_info(device, DeviceInfo::MaxWorkGroupSize) {
+        DeviceInfoResult::MaxWorkGroupSize(s) => s,
+        r @ _ => panic!("{:?}", r),
+    };
+
+    // Valid:
+    ::validate_work_dims(device, &[1024], None).unwrap();
+    ::validate_work_dims(device, &[1024], Some(&[1])).unwrap();
+
+    // Too many dimensions:
+    let global = vec![1; max_dims + 1];
+    assert_err_contains(::validate_work_dims(device, &global, None), "MaxWorkItemDimensions");
+
+    // A local dimension exceeding the work-item size:
+    let global = [max_sizes[0] * 2];
+    assert_err_contains(::validate_work_dims(device, &global, Some(&[max_sizes[0] + 1])),
+        "MaxWorkItemSizes[0]");
+
+    // A local work-group exceeding the work-group size (if expressible):
+    if max_dims >= 2 && max_sizes[0].saturating_mul(max_sizes[1]) > max_group_size {
+        let local = [max_sizes[0], max_sizes[1]];
+        assert_err_contains(::validate_work_dims(device, &local, Some(&local)),
+            "MaxWorkGroupSize");
+    }
+
+    // Zero-sized global dimension:
+    assert!(::validate_work_dims(device, &[0], None).is_err());
+}
+
+#[test]
+fn max_work_item_sizes() {
+    let platform_id = ::default_platform().unwrap();
+    let device_ids = ::get_device_ids(&platform_id, None, None).unwrap();
+
+    for &device in device_ids.iter() {
+        let max_dims = match ::get_device_info(device, DeviceInfo::MaxWorkItemDimensions) {
+            DeviceInfoResult::MaxWorkItemDimensions(d) => d as usize,
+            r @ _ => panic!("{:?}", r),
+        };
+
+        let sizes = ::get_device_max_work_item_sizes(device).unwrap();
+        assert_eq!(sizes.len(), max_dims);
+        assert!(sizes.iter().all(|&s| s > 0));
+    }
+}
+
+#[test]
+fn enqueue_kernel_ndrange_offset() {
+    const WIDTH: usize = 8;
+    const HEIGHT: usize = 8;
+
+    let src = r#"
+        __kernel void mark(__global uint* buffer) {
+            size_t idx = get_global_id(1) * get_global_size(0) + get_global_id(0);
+            buffer[idx] = 1;
+        }
+    "#;
+
+    let (device, context, program) = build_program(src);
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+
+    // Sized for the full offset range so that the global size is unaffected
+    // by the offset (`get_global_size` excludes it):
+    let len = (WIDTH * 2) * (HEIGHT * 2);
+    let buffer = unsafe { ::create_buffer::<_, u32>(&context, ::MEM_READ_WRITE, len, None)
+        .unwrap() };
+    ::enqueue_fill_buffer::<u32, _, _, _>(&queue, &buffer, 0, 0, len,
+        None::<::Event>, None::<&mut ::Event>, None).unwrap();
+
+    let kernel = ::create_kernel(&program, "mark").unwrap();
+    ::set_kernel_arg(&kernel, 0, ::KernelArg::Mem::<u32>(&buffer)).unwrap();
+
+    // Valid 2D launch with an offset of one in each dimension:
+    unsafe { ::enqueue_kernel_ndrange(&queue, &kernel, Some(&[1, 1][..]), &[WIDTH, HEIGHT], None,
+        None::<::Event>, None::<&mut ::Event>).unwrap(); }
+
+    let mut vec = vec![0u32; len];
+    unsafe { ::enqueue_read_buffer(&queue, &buffer, true, 0, &mut vec,
+        None::<::Event>, None::<&mut ::Event>).unwrap() };
+
+    let mut expected = vec![0u32; len];
+    for y in 1..(HEIGHT + 1) {
+        for x in 1..(WIDTH + 1) {
+            expected[y * WIDTH + x] = 1;
+        }
+    }
+    assert_eq!(vec, expected);
+
+    // Mismatched dimension counts are rejected before reaching the driver:
+    let res = unsafe { ::enqueue_kernel_ndrange(&queue, &kernel, Some(&[1][..]), &[WIDTH, HEIGHT],
+        None, None::<::Event>, None::<&mut ::Event>) };
+    assert!(format!("{}", res.err().unwrap()).contains("global work offset"));
+
+    let res = unsafe { ::enqueue_kernel_ndrange(&queue, &kernel, None, &[WIDTH, HEIGHT],
+        Some(&[1, 1, 1][..]), None::<::Event>, None::<&mut ::Event>) };
+    assert!(format!("{}", res.err().unwrap()).contains("local work size"));
+
+    let res = unsafe { ::enqueue_kernel_ndrange(&queue, &kernel, None, &[1, 1, 1, 1], None,
+        None::<::Event>, None::<&mut ::Event>) };
+    assert!(res.is_err());
+}
+
+#[test]
+fn enqueue_kernel_auto_non_power_of_two() {
+    let src = r#"
+        __kernel void add_idx(__global uint* buffer) {
+            buffer[get_global_id(0)] += get_global_id(0);
+        }
+    "#;
+
+    let (device, context, program) = build_program(src);
+    let queue = ::create_command_queue(&context, &device, None).unwrap();
+    let kernel = ::create_kernel(&program, "add_idx").unwrap();
+
+    // Divisible by small factors only, and prime (the driver chooses):
+    for &len in &[1000usize, 997] {
+        let buffer = unsafe { ::create_buffer::<_, u32>(&context, ::MEM_READ_WRITE, len, None)
+            .unwrap() };
+        ::enqueue_fill_buffer::<u32, _, _, _>(&queue, &buffer, 0, 0, len,
+            None::<::Event>, None::<&mut ::Event>, None).unwrap();
+        ::set_kernel_arg(&kernel, 0, ::KernelArg::Mem::<u32>(&buffer)).unwrap();
+
+        unsafe { ::enqueue_kernel_auto(&queue, &kernel, &[len], None::<::Event>,
+            None::<&mut ::Event>).unwrap(); }
+
+        let mut vec = vec![0u32; len];
+        unsafe { ::enqueue_read_buffer(&queue, &buffer, true, 0, &mut vec,
+            None::<::Event>, None::<&mut ::Event>).unwrap() };
+
+        assert!(vec.iter().enumerate().all(|(i, &v)| v == i as u32));
+    }
+}
+
+#[test]
+fn kernel_work_group_info() {
+    use ::{KernelWorkGroupInfo, KernelWorkGroupInfoResult};
+
+    let src = r#"
+        __kernel __attribute__((reqd_work_group_size(4, 1, 1)))
+        void fixed(__global uint* buffer) {
+            buffer[get_global_id(0)] = 1;
+        }
+    "#;
+
+    let (device, _context, program) = build_program(src);
+    let kernel = ::create_kernel(&program, "fixed").unwrap();
+
+    match ::get_kernel_work_group_info(&kernel, device, KernelWorkGroupInfo::WorkGroupSize) {
+        KernelWorkGroupInfoResult::WorkGroupSize(s) => assert!(s >= 1),
+        KernelWorkGroupInfoResult::Unavailable(_) => (),
+        r @ _ => panic!("Unexpected result: {:?}", r),
+    }
+
+    match ::get_kernel_work_group_info(&kernel, device,
+            KernelWorkGroupInfo::CompileWorkGroupSize) {
+        KernelWorkGroupInfoResult::CompileWorkGroupSize(s) => assert_eq!(s, [4, 1, 1]),
+        KernelWorkGroupInfoResult::Unavailable(_) => (),
+        r @ _ => panic!("Unexpected result: {:?}", r),
+    }
+
+    match ::get_kernel_work_group_info(&kernel, device,
+            KernelWorkGroupInfo::PreferredWorkGroupSizeMultiple) {
+        KernelWorkGroupInfoResult::PreferredWorkGroupSizeMultiple(s) => assert!(s >= 1),
+        KernelWorkGroupInfoResult::Unavailable(_) => (),
+        r @ _ => panic!("Unexpected result: {:?}", r),
+    }
+
+    for &request in &[KernelWorkGroupInfo::LocalMemSize, KernelWorkGroupInfo::PrivateMemSize] {
+        match ::get_kernel_work_group_info(&kernel, device, request) {
+            KernelWorkGroupInfoResult::LocalMemSize(_) |
+                KernelWorkGroupInfoResult::PrivateMemSize(_) |
+                KernelWorkGroupInfoResult::Unavailable(_) => (),
+            r @ _ => panic!("Unexpected result: {:?}", r),
+        }
+    }
+}
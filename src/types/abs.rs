@@ -42,14 +42,17 @@ use std::mem;
 use std::ptr;
 use std::slice;
 use std::cell::Ref;
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
 use libc::c_void;
 use ffi::{cl_platform_id, cl_device_id,  cl_context, cl_command_queue, cl_mem, cl_program,
     cl_kernel, cl_event, cl_sampler};
 use ::{CommandExecutionStatus, OpenclVersion, PlatformInfo, DeviceInfo, DeviceInfoResult,
     ContextInfo, ContextInfoResult, CommandQueueInfo, CommandQueueInfoResult, ProgramInfo,
     ProgramInfoResult, KernelInfo, KernelInfoResult, Status, EventCallbackFn, OclPrm,
-    EventInfo, EventInfoResult};
+    EventInfo, EventInfoResult, MemInfo, MemInfoResult, MemObjectType, MapFlags, MemFlags,
+    PlatformInfoResult, ProfilingSink};
 use error::{Result as OclResult, Error as OclError, ErrorKind as OclErrorKind};
 use functions;
 
@@ -353,6 +356,15 @@ impl PlatformId {
             OclError::err_string("PlatformId::version(): This platform_id is invalid.")
         }
     }
+
+    /// Returns the queried name of this platform.
+    pub fn name(&self) -> OclResult<String> {
+        match functions::get_platform_info(self, PlatformInfo::Name) {
+            PlatformInfoResult::Name(name) => Ok(name),
+            PlatformInfoResult::Error(err) => Err(*err),
+            _ => unreachable!(),
+        }
+    }
 }
 
 unsafe impl ClPlatformIdPtr for PlatformId {
@@ -415,6 +427,15 @@ impl DeviceId {
             OclError::err_string("DeviceId::device_versions(): This device_id is invalid.")
         }
     }
+
+    /// Returns the queried name of this device.
+    pub fn name(&self) -> OclResult<String> {
+        match functions::get_device_info(self, DeviceInfo::Name) {
+            DeviceInfoResult::Name(name) => Ok(name),
+            DeviceInfoResult::Error(err) => Err(*err),
+            _ => unreachable!(),
+        }
+    }
 }
 
 unsafe impl ClDeviceIdPtr for DeviceId {
@@ -558,23 +579,25 @@ impl ClVersions for Context {
 
 
 /// cl_command_queue
+///
+/// May carry a profiling sink (see `::set_profiling_sink`), which is copied
+/// to clones of this handle and dropped along with the last of them.
 #[repr(C)]
-#[derive(Debug)]
-pub struct CommandQueue(cl_command_queue);
+pub struct CommandQueue(cl_command_queue, Option<Arc<ProfilingSink>>);
 
 impl CommandQueue {
     /// Only call this when passing **the original** newly created pointer
     /// directly from `clCreate...`. Do not use this to clone or copy.
     pub unsafe fn from_raw_create_ptr(ptr: cl_command_queue) -> CommandQueue {
         assert!(!ptr.is_null(), "Null pointer passed.");
-        CommandQueue(ptr)
+        CommandQueue(ptr, None)
     }
 
     /// Only call this when passing a copied pointer such as from an
     /// `clGet*****Info` function.
     pub unsafe fn from_raw_copied_ptr(ptr: cl_command_queue) -> CommandQueue {
         assert!(!ptr.is_null(), "Null pointer passed.");
-        let copy = CommandQueue(ptr);
+        let copy = CommandQueue(ptr, None);
         functions::retain_command_queue(&copy).unwrap();
         copy
     }
@@ -602,12 +625,28 @@ impl CommandQueue {
     pub fn context_ptr(&self) -> OclResult<cl_context> {
         functions::get_command_queue_context_ptr(self)
     }
+
+    /// Returns the profiling sink attached to this handle, if any.
+    pub fn profiling_sink(&self) -> Option<&Arc<ProfilingSink>> {
+        self.1.as_ref()
+    }
+
+    /// Attaches (or with `None`, detaches) a profiling sink to this handle.
+    pub(crate) fn set_profiling_sink(&mut self, sink: Option<Arc<ProfilingSink>>) {
+        self.1 = sink;
+    }
 }
 
 impl Clone for CommandQueue {
     fn clone(&self) -> CommandQueue {
         unsafe { functions::retain_command_queue(self).unwrap(); }
-        CommandQueue(self.0)
+        CommandQueue(self.0, self.1.clone())
+    }
+}
+
+impl Debug for CommandQueue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("CommandQueue").field(&self.0).finish()
     }
 }
 
@@ -647,38 +686,132 @@ impl ClVersions for CommandQueue{
 
 
 /// cl_mem
+///
+/// The object type (buffer, image type, or pipe) and flags are recorded when
+/// the memory object is created by this crate. They are queried on demand
+/// for handles wrapped from raw pointers.
 #[repr(C)]
 #[derive(Debug)]
-pub struct Mem(cl_mem);
+pub struct Mem(cl_mem, Option<MemObjectType>, Option<MemFlags>);
 
 impl Mem {
     /// Only call this when passing **the original** newly created pointer
     /// directly from `clCreate...`. Do not use this to clone or copy.
     pub unsafe fn from_raw_create_ptr(ptr: cl_mem) -> Mem {
         assert!(!ptr.is_null(), "Null pointer passed.");
-        Mem(ptr)
+        Mem(ptr, None, None)
+    }
+
+    /// Same as `::from_raw_create_ptr` for a memory object whose type and
+    /// flags are already known.
+    pub(crate) unsafe fn from_raw_create_ptr_tagged(ptr: cl_mem, mem_type: MemObjectType,
+            flags: MemFlags) -> Mem
+    {
+        assert!(!ptr.is_null(), "Null pointer passed.");
+        Mem(ptr, Some(mem_type), Some(flags))
     }
 
 	/// Only call this when passing a copied pointer such as from an
 	/// `clGet*****Info` function.
 	pub unsafe fn from_raw_copied_ptr(ptr: cl_mem) -> Mem {
         assert!(!ptr.is_null(), "Null pointer passed.");
-		let copy = Mem(ptr);
+		let copy = Mem(ptr, None, None);
 		functions::retain_mem_object(&copy).unwrap();
 		copy
 	}
 
+    /// Queries and records the object type and flags of a newly created
+    /// memory object, leaving either unknown (to be queried again when
+    /// needed) if its query fails.
+    pub(crate) fn with_queried_info(mut self) -> Mem {
+        self.1 = self.query_type().ok();
+        self.2 = self.query_flags().ok();
+        self
+    }
+
+    fn query_type(&self) -> OclResult<MemObjectType> {
+        match functions::get_mem_object_info(self, MemInfo::Type) {
+            MemInfoResult::Type(mem_type) => Ok(mem_type),
+            MemInfoResult::Error(err) => Err(*err),
+            _ => unreachable!(),
+        }
+    }
+
     /// Returns a pointer, do not store it.
     #[inline(always)]
     pub fn as_ptr(&self) -> cl_mem {
         self.0
     }
+
+    /// Returns the type of this memory object (buffer, image type, or pipe)
+    /// recorded at creation, querying it if unknown.
+    pub fn mem_type(&self) -> OclResult<MemObjectType> {
+        match self.1 {
+            Some(mem_type) => Ok(mem_type),
+            None => self.query_type(),
+        }
+    }
+
+    /// Returns the flags this memory object was created with, querying them
+    /// if unknown.
+    pub fn flags(&self) -> OclResult<MemFlags> {
+        match self.2 {
+            Some(flags) => Ok(flags),
+            None => self.query_flags(),
+        }
+    }
+
+    fn query_flags(&self) -> OclResult<MemFlags> {
+        match functions::get_mem_object_info(self, MemInfo::Flags) {
+            MemInfoResult::Flags(flags) => Ok(flags),
+            MemInfoResult::Error(err) => Err(*err),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Queries and returns the size of this memory object's data store in
+    /// bytes.
+    pub fn size(&self) -> OclResult<usize> {
+        match functions::get_mem_object_info(self, MemInfo::Size) {
+            MemInfoResult::Size(size) => Ok(size),
+            MemInfoResult::Error(err) => Err(*err),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Queries and returns the offset in bytes of this sub-buffer within its
+    /// parent buffer (zero for anything other than a sub-buffer).
+    pub fn offset(&self) -> OclResult<usize> {
+        match functions::get_mem_object_info(self, MemInfo::Offset) {
+            MemInfoResult::Offset(offset) => Ok(offset),
+            MemInfoResult::Error(err) => Err(*err),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the size of this memory object's data store in bytes (same as
+    /// `::size`).
+    #[inline]
+    pub fn byte_size(&self) -> OclResult<usize> {
+        self.size()
+    }
+
+    /// Returns the number of elements of type `T` which fit within this
+    /// memory object's data store.
+    pub fn len<T: OclPrm>(&self) -> OclResult<usize> {
+        self.size().map(|size| size / mem::size_of::<T>())
+    }
+
+    /// Returns true if this memory object's data store has a size of zero.
+    pub fn is_empty(&self) -> OclResult<bool> {
+        self.size().map(|size| size == 0)
+    }
 }
 
 impl Clone for Mem {
     fn clone(&self) -> Mem {
         unsafe { functions::retain_mem_object(self).unwrap(); }
-        Mem(self.0)
+        Mem(self.0, self.1, self.2)
     }
 }
 
@@ -763,6 +896,189 @@ unsafe impl<T: OclPrm> Send for MemMap<T> {}
 // unsafe impl<T: OclPrm> Sync for MemMap<T> {}
 
 
+/// A mapped region of a buffer which is unmapped when dropped.
+///
+/// Dereferences to a slice of the mapped region. Mutable access is only
+/// available if the buffer was mapped with `MAP_WRITE` or
+/// `MAP_WRITE_INVALIDATE_REGION` (and will panic otherwise).
+///
+/// The guard holds a retained reference to both the command queue and the
+/// buffer for as long as it exists. Dropping it enqueues an unmap command
+/// and blocks until it completes, so that changes made through the guard
+/// are visible to any queue (and the host) once the guard is gone. Errors
+/// encountered while unmapping are ignored.
+pub struct MappedMem<T: OclPrm> {
+    map: MemMap<T>,
+    len: usize,
+    writable: bool,
+    buffer: Mem,
+    queue: CommandQueue,
+}
+
+impl<T: OclPrm> MappedMem<T> {
+    /// Creates a new guard from a mapped region of `len` elements.
+    ///
+    /// `map` must have been mapped from `buffer` using `queue` with
+    /// `map_flags` and must not be unmapped elsewhere.
+    pub unsafe fn from_raw(map: MemMap<T>, len: usize, map_flags: MapFlags, buffer: Mem,
+            queue: CommandQueue) -> MappedMem<T>
+    {
+        MappedMem {
+            map: map,
+            len: len,
+            writable: map_flags.contains(::MAP_WRITE) ||
+                map_flags.contains(::MAP_WRITE_INVALIDATE_REGION),
+            buffer: buffer,
+            queue: queue,
+        }
+    }
+
+    /// Returns the length of the mapped region in elements.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the mapped region is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the region was mapped for writing.
+    #[inline]
+    pub fn is_writable(&self) -> bool {
+        self.writable
+    }
+}
+
+impl<T: OclPrm> Deref for MappedMem<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { self.map.as_slice(self.len) }
+    }
+}
+
+impl<T: OclPrm> DerefMut for MappedMem<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        assert!(self.writable, "MappedMem::deref_mut: This region was not mapped for writing \
+            (use 'MAP_WRITE' or 'MAP_WRITE_INVALIDATE_REGION').");
+        unsafe { self.map.as_slice_mut(self.len) }
+    }
+}
+
+impl<T: OclPrm> Drop for MappedMem<T> {
+    fn drop(&mut self) {
+        // Panicking here (possibly while already unwinding) would abort:
+        let mut unmap_event = Event::null();
+        if functions::enqueue_unmap_mem_object(&self.queue, &self.buffer, &self.map,
+                None::<Event>, Some(&mut unmap_event)).is_ok() {
+            let _ = functions::wait_for_event(&unmap_event);
+        }
+    }
+}
+
+
+/// An opt-in host-side cache of a region of a buffer.
+///
+/// `::read` performs a blocking read only if the buffer may have been
+/// written to since the last read and otherwise returns the cached copy.
+///
+/// ### Invalidation
+///
+/// Only operations issued through this crate are tracked. Writes, fills,
+/// copies (into the buffer) and unmaps invalidate the cache of the specific
+/// memory object they target. Kernels, native kernels and GL object
+/// acquisitions invalidate every cache within the same context since their
+/// memory arguments are not tracked. Sub-buffers and their parent buffers are
+/// tracked independently.
+///
+/// Anything done to the buffer outside of this crate (through raw pointers,
+/// other libraries, or shared host memory) is invisible: call
+/// `::invalidate` manually in those cases.
+///
+/// Tracking is only active while at least one `CachedReader` exists. Until
+/// then, enqueue functions do no extra work on its behalf.
+pub struct CachedReader<T: OclPrm> {
+    buffer: Mem,
+    queue: CommandQueue,
+    context: Context,
+    offset: usize,
+    cache: Vec<T>,
+    generation: Option<(usize, usize)>,
+    transfers: usize,
+}
+
+impl<T: OclPrm> CachedReader<T> {
+    /// Creates a new reader for the `len` elements of `buffer` starting at
+    /// `offset`. No data is read until `::read` is first called.
+    ///
+    /// Panics if the context of `queue` can not be queried.
+    pub fn new(queue: &CommandQueue, buffer: &Mem, offset: usize, len: usize) -> CachedReader<T> {
+        let context = queue.context().expect("CachedReader::new: Invalid command queue");
+        functions::track_mem_generation(&context, buffer);
+
+        CachedReader {
+            buffer: buffer.clone(),
+            queue: queue.clone(),
+            context: context,
+            offset: offset,
+            cache: vec![T::default(); len],
+            generation: None,
+            transfers: 0,
+        }
+    }
+
+    /// Returns the contents of the region, reading from the device only if
+    /// the cached copy may be stale.
+    pub fn read(&mut self) -> OclResult<&[T]> {
+        let generation = functions::mem_generation(&self.context, &self.buffer);
+
+        if self.generation != Some(generation) {
+            unsafe {
+                functions::enqueue_read_buffer(&self.queue, &self.buffer, true, self.offset,
+                    &mut self.cache, None::<Event>, None::<&mut Event>)?;
+            }
+            self.generation = Some(generation);
+            self.transfers += 1;
+        }
+
+        Ok(&self.cache)
+    }
+
+    /// Forces the next call to `::read` to read from the device.
+    #[inline]
+    pub fn invalidate(&mut self) {
+        self.generation = None;
+    }
+
+    /// Returns the number of device-to-host transfers performed so far.
+    #[inline]
+    pub fn transfer_count(&self) -> usize {
+        self.transfers
+    }
+
+    /// Returns the length of the cached region in elements.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Returns true if the cached region is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+impl<T: OclPrm> Drop for CachedReader<T> {
+    fn drop(&mut self) {
+        functions::untrack_mem_generation(&self.context, &self.buffer);
+    }
+}
+
+
 
 /// cl_program
 #[repr(C)]
@@ -1174,6 +1490,101 @@ unsafe impl Sync for Event {}
 unsafe impl Send for Event {}
 
 
+/// A list of events which can be passed directly as a wait list.
+///
+/// Each event pushed is retained and released again when the list is
+/// dropped or cleared, so the list never holds a dangling `cl_event`.
+#[derive(Debug, Clone, Default)]
+pub struct EventList {
+    events: Vec<Event>,
+}
+
+impl EventList {
+    /// Returns a new, empty list.
+    #[inline]
+    pub fn new() -> EventList {
+        EventList { events: Vec::new() }
+    }
+
+    /// Returns a new, empty list with room for `capacity` events.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> EventList {
+        EventList { events: Vec::with_capacity(capacity) }
+    }
+
+    /// Retains and adds an event to the list.
+    ///
+    /// Null events (ones never used as the target of an enqueue) are
+    /// ignored since they cannot be waited on.
+    pub fn push<E: AsRef<Event>>(&mut self, event: E) {
+        let event = event.as_ref();
+        if !event.is_null() {
+            self.events.push(event.clone());
+        }
+    }
+
+    /// Removes and returns the last event in the list, if any.
+    #[inline]
+    pub fn pop(&mut self) -> Option<Event> {
+        self.events.pop()
+    }
+
+    /// Releases every event in the list.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.events.clear()
+    }
+
+    /// Blocks until every event in the list is complete.
+    pub fn wait(&self) -> OclResult<()> {
+        functions::wait_for_events(self.events.len() as u32, &self.events.as_slice())
+    }
+
+    /// Consumes the list, returning the contained events.
+    #[inline]
+    pub fn into_vec(self) -> Vec<Event> {
+        self.events
+    }
+}
+
+impl Deref for EventList {
+    type Target = [Event];
+
+    #[inline]
+    fn deref(&self) -> &[Event] {
+        &self.events
+    }
+}
+
+impl<'a> From<&'a [Event]> for EventList {
+    fn from(events: &'a [Event]) -> EventList {
+        let mut list = EventList::with_capacity(events.len());
+        for event in events { list.push(event); }
+        list
+    }
+}
+
+impl<'a> IntoIterator for &'a EventList {
+    type Item = &'a Event;
+    type IntoIter = slice::Iter<'a, Event>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.events.iter()
+    }
+}
+
+unsafe impl<'a> ClWaitListPtr for &'a EventList {
+    #[inline(always)] unsafe fn as_ptr_ptr(&self) -> *const cl_event {
+        self.events.as_ptr() as *const _ as *const cl_event
+    }
+
+    #[inline(always)] fn count(&self) -> u32 {
+        self.events.len() as u32
+    }
+}
+
+
 /// cl_sampler
 #[repr(C)]
 #[derive(Debug)]
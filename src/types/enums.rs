@@ -17,17 +17,17 @@ use std;
 use libc::{size_t, c_void};
 use num::FromPrimitive;
 use util;
-use ffi::{cl_image_format, cl_context_properties};
+use ffi::{cl_image_format, cl_context_properties, cl_device_partition_property};
 
 use ::{OclPrm, CommandQueueProperties, PlatformId, PlatformInfo, DeviceId, DeviceInfo, ContextInfo,
-    GlContextInfo, Context, CommandQueue, CommandQueueInfo, CommandType, CommandExecutionStatus,
-    Mem, MemInfo, MemObjectType, MemFlags, Sampler, SamplerInfo, AddressingMode, FilterMode,
+    GlContextInfo, GlTextureInfo, Context, CommandQueue, CommandQueueInfo, CommandType,
+    CommandExecutionStatus, Mem, MemInfo, MemObjectType, MemFlags, Sampler, SamplerInfo, AddressingMode, FilterMode,
     ProgramInfo, ProgramBuildInfo, Program, ProgramBuildStatus, ProgramBinaryType, KernelInfo,
     KernelArgInfo, KernelWorkGroupInfo, KernelArgAddressQualifier, KernelArgAccessQualifier,
     KernelArgTypeQualifier, ImageInfo, ImageFormat, EventInfo, ProfilingInfo, DeviceType,
     DeviceFpConfig, DeviceMemCacheType, DeviceLocalMemType, DeviceExecCapabilities,
-    DevicePartitionProperty, DeviceAffinityDomain, OpenclVersion, ContextProperties,
-    ImageFormatParseResult, Status};
+    DeviceSvmCapabilities, DevicePartitionProperty, DeviceAffinityDomain, OpenclVersion,
+    ContextProperties, ImageFormatParseResult, Status};
 
 use error::{Result as OclResult, Error as OclError};
 // use cl_h;
@@ -43,11 +43,30 @@ macro_rules! try_ir {
     };
 }
 
+/// Decodes a list of `cl_name_version` structs (OpenCL 3.0), each a packed
+/// `cl_version` followed by a 64 byte, null-terminated name.
+fn name_versions_from_bytes(result: &[u8]) -> OclResult<Vec<(String, OpenclVersion)>> {
+    const NAME_VERSION_SIZE: usize = 4 + 64;
+
+    if result.len() % NAME_VERSION_SIZE != 0 {
+        return OclError::err_string(format!("Invalid 'cl_name_version' list size: {} bytes.",
+            result.len()));
+    }
+
+    Ok(result.chunks(NAME_VERSION_SIZE).map(|nv| {
+        let version = util::bytes_to_u32(&nv[..4]);
+        let name_len = nv[4..].iter().position(|&b| b == 0).unwrap_or(NAME_VERSION_SIZE - 4);
+        (String::from_utf8_lossy(&nv[4..4 + name_len]).into_owned(),
+            OpenclVersion::from_cl_version(version))
+    }).collect())
+}
+
 pub enum EmptyInfoResult {
     Platform,
     Device,
     Context,
     GlContext,
+    GlTexture,
     CommandQueue,
     Mem,
     Image,
@@ -82,6 +101,7 @@ impl std::error::Error for EmptyInfoResult {
             EmptyInfoResult::Device => "device info unavailable",
             EmptyInfoResult::Context => "context info unavailable",
             EmptyInfoResult::GlContext => "OpenGL context info unavailable",
+            EmptyInfoResult::GlTexture => "OpenGL texture info unavailable",
             EmptyInfoResult::CommandQueue => "command queue info unavailable",
             EmptyInfoResult::Mem => "mem info unavailable",
             EmptyInfoResult::Image => "image info unavailable",
@@ -98,6 +118,112 @@ impl std::error::Error for EmptyInfoResult {
 }
 
 
+/// The profile supported by a platform or device.
+///
+/// Embedded profile devices may lack features which are otherwise mandatory
+/// (64-bit integers and some floating point rounding modes, for example).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    Full,
+    Embedded,
+}
+
+impl Profile {
+    /// Parses a `PlatformInfo::Profile` or `DeviceInfo::Profile` string
+    /// ('FULL_PROFILE' or 'EMBEDDED_PROFILE').
+    pub fn from_info_str(profile: &str) -> OclResult<Profile> {
+        match profile.trim_right_matches('\0').trim() {
+            "FULL_PROFILE" => Ok(Profile::Full),
+            "EMBEDDED_PROFILE" => Ok(Profile::Embedded),
+            p @ _ => OclError::err_string(format!("Profile::from_info_str: Unknown profile: \
+                '{}'.", p)),
+        }
+    }
+
+    /// Returns true if this is the embedded profile.
+    pub fn is_embedded(&self) -> bool {
+        *self == Profile::Embedded
+    }
+}
+
+
+/// A scheme for partitioning a device into sub-devices (see
+/// `::create_sub_devices`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum DevicePartition {
+    /// As many sub-devices as possible, each containing the specified number
+    /// of compute units.
+    Equally(u32),
+    /// One sub-device for each count, containing that many compute units.
+    ByCounts(Vec<u32>),
+    /// Sub-devices sharing the specified level of the cache hierarchy (or
+    /// NUMA node).
+    ByAffinityDomain(DeviceAffinityDomain),
+}
+
+impl DevicePartition {
+    /// Returns a zero-terminated list of raw `cl_device_partition_property`.
+    pub fn to_raw(&self) -> Vec<cl_device_partition_property> {
+        let mut props = Vec::with_capacity(4);
+
+        match *self {
+            DevicePartition::Equally(cu_count) => {
+                props.push(DevicePartitionProperty::Equally as cl_device_partition_property);
+                props.push(cu_count as cl_device_partition_property);
+            },
+            DevicePartition::ByCounts(ref counts) => {
+                props.push(DevicePartitionProperty::ByCounts as cl_device_partition_property);
+                props.extend(counts.iter().map(|&c| c as cl_device_partition_property));
+                props.push(DevicePartitionProperty::ByCountsListEnd as
+                    cl_device_partition_property);
+            },
+            DevicePartition::ByAffinityDomain(domain) => {
+                props.push(DevicePartitionProperty::ByAffinityDomain as
+                    cl_device_partition_property);
+                props.push(domain.bits() as cl_device_partition_property);
+            },
+        }
+
+        props.push(0);
+        props
+    }
+}
+
+
+/// The execution status of the command associated with an event (see
+/// `::get_event_status`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventStatus {
+    Queued,
+    Submitted,
+    Running,
+    Complete,
+    /// The command was terminated abnormally. Contains the (negative) error
+    /// code reported in place of a status.
+    Error(i32),
+}
+
+impl EventStatus {
+    /// Decodes a raw `CL_EVENT_COMMAND_EXECUTION_STATUS` value. Returns
+    /// `None` for unknown non-negative values.
+    pub fn from_i32(status: i32) -> Option<EventStatus> {
+        if status < 0 { return Some(EventStatus::Error(status)); }
+
+        CommandExecutionStatus::from_i32(status).map(|s| match s {
+            CommandExecutionStatus::Queued => EventStatus::Queued,
+            CommandExecutionStatus::Submitted => EventStatus::Submitted,
+            CommandExecutionStatus::Running => EventStatus::Running,
+            CommandExecutionStatus::Complete => EventStatus::Complete,
+        })
+    }
+
+    /// Returns true if the command has completed successfully.
+    pub fn is_complete(&self) -> bool {
+        *self == EventStatus::Complete
+    }
+}
+
+
 /// [UNSAFE] Kernel argument option type.
 ///
 /// The type argument `T` is ignored for `Mem`, `Sampler`, and `UnsafePointer`
@@ -152,6 +278,8 @@ pub enum PlatformInfoResult {
     Name(String),
     Vendor(String),
     Extensions(String),
+    HostTimerResolution(u64),
+    ExtensionsWithVersion(Vec<(String, OpenclVersion)>),
     Error(Box<OclError>),
 }
 
@@ -166,6 +294,16 @@ impl PlatformInfoResult {
                         EmptyInfoResult::Platform)));
                 }
 
+                if request == PlatformInfo::HostTimerResolution {
+                    let r = unsafe { try_ir!(util::bytes_into::<u64>(result)) };
+                    return PlatformInfoResult::HostTimerResolution(r);
+                }
+
+                if request == PlatformInfo::ExtensionsWithVersion {
+                    let exts = try_ir!(name_versions_from_bytes(&result));
+                    return PlatformInfoResult::ExtensionsWithVersion(exts);
+                }
+
                 let string = match util::bytes_into_string(result) {
                     Ok(s) => s,
                     Err(err) => return PlatformInfoResult::Error(Box::new(err)),
@@ -177,6 +315,8 @@ impl PlatformInfoResult {
                     PlatformInfo::Name => PlatformInfoResult::Name(string),
                     PlatformInfo::Vendor => PlatformInfoResult::Vendor(string),
                     PlatformInfo::Extensions => PlatformInfoResult::Extensions(string),
+                    PlatformInfo::HostTimerResolution |
+                        PlatformInfo::ExtensionsWithVersion => unreachable!(),
                 }
             }
             Err(err) => PlatformInfoResult::Error(Box::new(err)),
@@ -193,6 +333,26 @@ impl PlatformInfoResult {
                 'PlatformInfoResult::Version' variant.", self))
         }
     }
+
+    /// Returns the value of any string variant or `None` for any other
+    /// variant.
+    pub fn as_string(&self) -> Option<&str> {
+        match *self {
+            PlatformInfoResult::Profile(ref v) | PlatformInfoResult::Version(ref v) |
+            PlatformInfoResult::Name(ref v) | PlatformInfoResult::Vendor(ref v) |
+            PlatformInfoResult::Extensions(ref v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the `HostTimerResolution` variant or `None` for
+    /// any other variant.
+    pub fn as_u64(&self) -> Option<u64> {
+        match *self {
+            PlatformInfoResult::HostTimerResolution(v) => Some(v),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Debug for PlatformInfoResult {
@@ -209,6 +369,12 @@ impl std::fmt::Display for PlatformInfoResult {
             PlatformInfoResult::Name(ref s) => write!(f, "{}", s),
             PlatformInfoResult::Vendor(ref s) => write!(f, "{}", s),
             PlatformInfoResult::Extensions(ref s) => write!(f, "{}", s),
+            PlatformInfoResult::HostTimerResolution(ref s) => write!(f, "{}", s),
+            PlatformInfoResult::ExtensionsWithVersion(ref exts) => {
+                let exts: Vec<String> = exts.iter()
+                    .map(|&(ref name, ref ver)| format!("{}_{}", name, ver)).collect();
+                write!(f, "{}", exts.join(" "))
+            },
             PlatformInfoResult::Error(ref err) => write!(f, "{}", err),
         }
     }
@@ -222,6 +388,8 @@ impl From<PlatformInfoResult> for String {
             | PlatformInfoResult::Name(string)
             | PlatformInfoResult::Vendor(string)
             | PlatformInfoResult::Extensions(string) => string,
+            PlatformInfoResult::HostTimerResolution(r) => r.to_string(),
+            r @ PlatformInfoResult::ExtensionsWithVersion(_) => r.to_string(),
             PlatformInfoResult::Error(err) => err.to_string(),
         }
     }
@@ -320,6 +488,7 @@ pub enum DeviceInfoResult {
     Version(OpenclVersion),
     Extensions(String),               // String
     Platform(PlatformId),             // cl_platform_id
+    // Empty if double (or half) precision is unsupported:
     DoubleFpConfig(DeviceFpConfig),           // cl_device_fp_config    FLAGS u64
     HalfFpConfig(DeviceFpConfig),             // cl_device_fp_config    FLAGS u64
     PreferredVectorWidthHalf(u32), // cl_uint
@@ -346,6 +515,9 @@ pub enum DeviceInfoResult {
     PrintfBufferSize(usize),         // usize
     ImagePitchAlignment(u32),      // cl_uint
     ImageBaseAddressAlignment(u32),// cl_uint
+    SvmCapabilities(DeviceSvmCapabilities), // cl_device_svm_capabilities    FLAGS u64
+    IlVersion(String),                // String
+    IlsWithVersion(Vec<(String, OpenclVersion)>), // [cl_name_version]
     Error(Box<OclError>),
 }
 
@@ -362,34 +534,15 @@ impl DeviceInfoResult {
                 }
             match request {
                 DeviceInfo::MaxWorkItemSizes => {
-                    match max_wi_dims {
-                        3 => {
-                            // let r = match unsafe { try_ir!(util::bytes_into::<[usize; 3]>(result)) } {
-                            //     Ok(r) => r,
-                            //     Err(err) => return err.into(),
-                            // };
+                    let r = unsafe { try_ir!(util::bytes_into_vec::<usize>(result)) };
 
-                            let r = unsafe { try_ir!(util::bytes_into::<[usize; 3]>(result)) };
-
-                            let mut v = Vec::with_capacity(3);
-                            v.extend_from_slice(&r);
-                            DeviceInfoResult::MaxWorkItemSizes(v)
-                        },
-                        2 => {
-                            let r = unsafe { try_ir!(util::bytes_into::<[usize; 2]>(result)) };
-                            let mut v = Vec::with_capacity(2);
-                            v.extend_from_slice(&r);
-                            DeviceInfoResult::MaxWorkItemSizes(v)
-                        },
-                        1 => {
-                            let r = unsafe { try_ir!(util::bytes_into::<[usize; 1]>(result)) };
-                            let mut v = Vec::with_capacity(1);
-                            v.extend_from_slice(&r);
-                            DeviceInfoResult::MaxWorkItemSizes(v)
-                        },
-                        _ => DeviceInfoResult::Error(Box::new(OclError::from("Error \
-                            determining number of dimensions for MaxWorkItemSizes."))),
+                    if max_wi_dims == 0 || r.len() != max_wi_dims as usize {
+                        return DeviceInfoResult::Error(Box::new(OclError::from(format!("Error \
+                            decoding MaxWorkItemSizes: the device reports {} dimensions but \
+                            returned {} sizes.", max_wi_dims, r.len()))));
                     }
+
+                    DeviceInfoResult::MaxWorkItemSizes(r)
                 },
                 _ => panic!("DeviceInfoResult::from_bytes_max_work_item_sizes: Called with \
                     invalid info variant ({:?}). Call '::from_bytes` instead.", request),
@@ -712,16 +865,20 @@ impl DeviceInfoResult {
                         DeviceInfoResult::PartitionMaxSubDevices(r)
                     },
                     DeviceInfo::PartitionProperties => {
-                        // [FIXME]: INCOMPLETE:
-                        //
-                        // let r = unsafe { try_ir!(util::bytes_into::<u32>(result)) };
-                        // match DevicePartitionProperty::from_u32(r) {
-                        //     Some(e) => DeviceInfoResult::PartitionProperties(e),
-                        //     None => DeviceInfoResult::Error(Box::new(
-                        //         OclError::from(format!("Error converting '{:X}' to \
-                        //             DevicePartitionProperty.", r)))),
-                        // }
-                        DeviceInfoResult::PartitionProperties(Vec::with_capacity(0))
+                        // Devices which cannot be partitioned report a single zero:
+                        let raw = unsafe { try_ir!(util::bytes_into_vec::<isize>(result)) };
+                        let mut props = Vec::with_capacity(raw.len());
+
+                        for &r in raw.iter().take_while(|&&r| r != 0) {
+                            match DevicePartitionProperty::from_isize(r) {
+                                Some(p) => props.push(p),
+                                None => return DeviceInfoResult::Error(Box::new(
+                                    OclError::from(format!("Error converting '{:X}' to \
+                                        DevicePartitionProperty.", r)))),
+                            }
+                        }
+
+                        DeviceInfoResult::PartitionProperties(props)
                     },
                     DeviceInfo::PartitionAffinityDomain => {
                         let r = unsafe { try_ir!(util::bytes_into::<DeviceAffinityDomain>(result)) };
@@ -759,6 +916,20 @@ impl DeviceInfoResult {
                         let r = unsafe { try_ir!(util::bytes_into::<u32>(result)) };
                         DeviceInfoResult::ImageBaseAddressAlignment(r)
                     },
+                    DeviceInfo::SvmCapabilities => {
+                        let r = unsafe { try_ir!(util::bytes_into::<DeviceSvmCapabilities>(result)) };
+                        DeviceInfoResult::SvmCapabilities(r)
+                    },
+                    DeviceInfo::IlVersion => {
+                        match util::bytes_into_trimmed_string(result) {
+                            Ok(s) => DeviceInfoResult::IlVersion(s),
+                            Err(err) => DeviceInfoResult::Error(Box::new(err)),
+                        }
+                    },
+                    DeviceInfo::IlsWithVersion => {
+                        let ils = try_ir!(name_versions_from_bytes(&result));
+                        DeviceInfoResult::IlsWithVersion(ils)
+                    },
                     // _ => DeviceInfoResult::TemporaryPlaceholderVariant(result),
                 }
             },
@@ -776,6 +947,99 @@ impl DeviceInfoResult {
                 'DeviceInfoResult::Version' variant.", self))
         }
     }
+
+    /// Returns the list of intermediate languages (e.g. `["SPIR-V_1.0",
+    /// "SPIR-V_1.2"]`) from an `IlVersion` (space-separated) or
+    /// `IlsWithVersion` variant.
+    pub fn as_il_versions(&self) -> OclResult<Vec<String>> {
+        match *self {
+            DeviceInfoResult::IlVersion(ref ils) => {
+                Ok(ils.split_whitespace().map(String::from).collect())
+            },
+            DeviceInfoResult::IlsWithVersion(ref ils) => {
+                Ok(ils.iter().map(|&(ref name, ref ver)| format!("{}_{}", name, ver)).collect())
+            },
+            _ => OclError::err_string(format!("DeviceInfoResult::as_il_versions(): Invalid \
+                device info result variant: ({:?}). This function can only be called on a \
+                'DeviceInfoResult::IlVersion' or 'DeviceInfoResult::IlsWithVersion' variant.",
+                self)),
+        }
+    }
+
+    /// Returns the value of any unsigned integer (`cl_uint` or `cl_ulong`)
+    /// variant, widened to `u64`, or `None` for any other variant.
+    pub fn as_u64(&self) -> Option<u64> {
+        match *self {
+            DeviceInfoResult::VendorId(v) | DeviceInfoResult::MaxComputeUnits(v) |
+            DeviceInfoResult::MaxWorkItemDimensions(v) |
+            DeviceInfoResult::PreferredVectorWidthChar(v) |
+            DeviceInfoResult::PreferredVectorWidthShort(v) |
+            DeviceInfoResult::PreferredVectorWidthInt(v) |
+            DeviceInfoResult::PreferredVectorWidthLong(v) |
+            DeviceInfoResult::PreferredVectorWidthFloat(v) |
+            DeviceInfoResult::PreferredVectorWidthDouble(v) |
+            DeviceInfoResult::MaxClockFrequency(v) | DeviceInfoResult::AddressBits(v) |
+            DeviceInfoResult::MaxReadImageArgs(v) | DeviceInfoResult::MaxWriteImageArgs(v) |
+            DeviceInfoResult::MaxSamplers(v) | DeviceInfoResult::MemBaseAddrAlign(v) |
+            DeviceInfoResult::MinDataTypeAlignSize(v) |
+            DeviceInfoResult::GlobalMemCachelineSize(v) | DeviceInfoResult::MaxConstantArgs(v) |
+            DeviceInfoResult::PreferredVectorWidthHalf(v) |
+            DeviceInfoResult::NativeVectorWidthChar(v) |
+            DeviceInfoResult::NativeVectorWidthShort(v) |
+            DeviceInfoResult::NativeVectorWidthInt(v) |
+            DeviceInfoResult::NativeVectorWidthLong(v) |
+            DeviceInfoResult::NativeVectorWidthFloat(v) |
+            DeviceInfoResult::NativeVectorWidthDouble(v) |
+            DeviceInfoResult::NativeVectorWidthHalf(v) |
+            DeviceInfoResult::PartitionMaxSubDevices(v) | DeviceInfoResult::ReferenceCount(v) |
+            DeviceInfoResult::ImagePitchAlignment(v) |
+            DeviceInfoResult::ImageBaseAddressAlignment(v) => Some(v as u64),
+            DeviceInfoResult::MaxMemAllocSize(v) | DeviceInfoResult::GlobalMemCacheSize(v) |
+            DeviceInfoResult::GlobalMemSize(v) | DeviceInfoResult::MaxConstantBufferSize(v) |
+            DeviceInfoResult::LocalMemSize(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of any `size_t` variant or `None` for any other
+    /// variant.
+    pub fn as_size(&self) -> Option<usize> {
+        match *self {
+            DeviceInfoResult::MaxWorkGroupSize(v) | DeviceInfoResult::Image2dMaxWidth(v) |
+            DeviceInfoResult::Image2dMaxHeight(v) | DeviceInfoResult::Image3dMaxWidth(v) |
+            DeviceInfoResult::Image3dMaxHeight(v) | DeviceInfoResult::Image3dMaxDepth(v) |
+            DeviceInfoResult::MaxParameterSize(v) | DeviceInfoResult::ProfilingTimerResolution(v) |
+            DeviceInfoResult::ImageMaxBufferSize(v) | DeviceInfoResult::ImageMaxArraySize(v) |
+            DeviceInfoResult::PrintfBufferSize(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of any `cl_bool` variant or `None` for any other
+    /// variant.
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            DeviceInfoResult::ImageSupport(v) | DeviceInfoResult::ErrorCorrectionSupport(v) |
+            DeviceInfoResult::EndianLittle(v) | DeviceInfoResult::Available(v) |
+            DeviceInfoResult::CompilerAvailable(v) | DeviceInfoResult::HostUnifiedMemory(v) |
+            DeviceInfoResult::LinkerAvailable(v) |
+            DeviceInfoResult::PreferredInteropUserSync(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of any string variant or `None` for any other
+    /// variant.
+    pub fn as_string(&self) -> Option<&str> {
+        match *self {
+            DeviceInfoResult::Name(ref v) | DeviceInfoResult::Vendor(ref v) |
+            DeviceInfoResult::DriverVersion(ref v) | DeviceInfoResult::Profile(ref v) |
+            DeviceInfoResult::Extensions(ref v) | DeviceInfoResult::OpenclCVersion(ref v) |
+            DeviceInfoResult::BuiltInKernels(ref v) |
+            DeviceInfoResult::IlVersion(ref v) => Some(v),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Debug for DeviceInfoResult {
@@ -867,6 +1131,13 @@ impl std::fmt::Display for DeviceInfoResult {
             DeviceInfoResult::PrintfBufferSize(ref s) => write!(f, "{}", s),
             DeviceInfoResult::ImagePitchAlignment(ref s) => write!(f, "{}", s),
             DeviceInfoResult::ImageBaseAddressAlignment(ref s) => write!(f, "{}", s),
+            DeviceInfoResult::SvmCapabilities(ref s) => write!(f, "{:?}", s),
+            DeviceInfoResult::IlVersion(ref s) => write!(f, "{}", s),
+            DeviceInfoResult::IlsWithVersion(ref ils) => {
+                let ils: Vec<String> = ils.iter()
+                    .map(|&(ref name, ref ver)| format!("{}_{}", name, ver)).collect();
+                write!(f, "{}", ils.join(" "))
+            },
             DeviceInfoResult::Error(ref err) => write!(f, "{}", err),
             // r @ _ => panic!("DeviceInfoResult: Converting '{:?}' to string not yet implemented.", r),
         }
@@ -922,6 +1193,10 @@ impl ContextInfoResult {
         match result {
             Ok(result) => {
                 if result.is_empty() {
+                    // A context created without properties reports none:
+                    if let ContextInfo::Properties = request {
+                        return ContextInfoResult::Properties(ContextProperties::new());
+                    }
                     return ContextInfoResult::Error(Box::new(OclError::from(
                         EmptyInfoResult::Context)));
                 }
@@ -1084,6 +1359,66 @@ impl From<OclError> for GlContextInfoResult {
     }
 }
 
+
+/// An OpenGL texture info result.
+pub enum GlTextureInfoResult {
+    TextureTarget(u32),         // cl_GLenum
+    MipmapLevel(i32),           // cl_GLint
+    Error(Box<OclError>),
+}
+
+impl GlTextureInfoResult {
+    pub fn from_bytes(request: GlTextureInfo, result: OclResult<Vec<u8>>) -> GlTextureInfoResult {
+        match result {
+            Ok(result) => {
+                if result.is_empty() {
+                    return GlTextureInfoResult::Error(Box::new(OclError::from(
+                        EmptyInfoResult::GlTexture)));
+                }
+                match request {
+                    GlTextureInfo::TextureTarget => {
+                        let r = unsafe { try_ir!(util::bytes_into::<u32>(result)) };
+                        GlTextureInfoResult::TextureTarget(r)
+                    },
+                    GlTextureInfo::MipmapLevel => {
+                        let r = unsafe { try_ir!(util::bytes_into::<i32>(result)) };
+                        GlTextureInfoResult::MipmapLevel(r)
+                    },
+                }
+            },
+            Err(err) => GlTextureInfoResult::Error(Box::new(err)),
+        }
+    }
+}
+
+impl std::fmt::Debug for GlTextureInfoResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", &self.to_string())
+    }
+}
+
+impl std::fmt::Display for GlTextureInfoResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            GlTextureInfoResult::TextureTarget(ref t) => write!(f, "{:#X}", t),
+            GlTextureInfoResult::MipmapLevel(ref l) => write!(f, "{}", l),
+            GlTextureInfoResult::Error(ref err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<GlTextureInfoResult> for String {
+    fn from(ir: GlTextureInfoResult) -> String {
+        ir.to_string()
+    }
+}
+
+impl From<OclError> for GlTextureInfoResult {
+    fn from(err: OclError) -> GlTextureInfoResult {
+        GlTextureInfoResult::Error(Box::new(err))
+    }
+}
+
 impl From<GlContextInfoResult> for OclError {
     fn from(err: GlContextInfoResult) -> OclError {
         match err {
@@ -1111,6 +1446,7 @@ pub enum CommandQueueInfoResult {
     Device(DeviceId),
     ReferenceCount(u32),
     Properties(CommandQueueProperties),
+    Size(u32),
     Error(Box<OclError>),
 }
 
@@ -1142,6 +1478,10 @@ impl CommandQueueInfoResult {
                         let r = unsafe { try_ir!(util::bytes_into::<CommandQueueProperties>(result)) };
                         CommandQueueInfoResult::Properties(r)
                     }
+                    CommandQueueInfo::Size => {
+                        let r = unsafe { try_ir!(util::bytes_into::<u32>(result)) };
+                        CommandQueueInfoResult::Size(r)
+                    }
                     // _ => CommandQueueInfoResult::TemporaryPlaceholderVariant(result),
                 }
             },
@@ -1166,6 +1506,7 @@ impl std::fmt::Display for CommandQueueInfoResult {
             CommandQueueInfoResult::Device(ref s) => write!(f, "{:?}", s),
             CommandQueueInfoResult::ReferenceCount(ref s) => write!(f, "{}", s),
             CommandQueueInfoResult::Properties(ref s) => write!(f, "{:?}", s),
+            CommandQueueInfoResult::Size(ref s) => write!(f, "{}", s),
             CommandQueueInfoResult::Error(ref err) => write!(f, "{}", err),
             // _ => panic!("CommandQueueInfoResult: Converting this variant to string not yet implemented."),
         }
@@ -1554,6 +1895,9 @@ pub enum SamplerInfoResult {
     NormalizedCoords(bool),
     AddressingMode(AddressingMode),
     FilterMode(FilterMode),
+    MipFilterMode(FilterMode),
+    LodMin(f32),
+    LodMax(f32),
     Error(Box<OclError>),
 }
 
@@ -1598,6 +1942,23 @@ impl SamplerInfoResult {
                                     FilterMode.", r)))),
                         }
                     },
+                    SamplerInfo::MipFilterMode => {
+                        let r = unsafe { try_ir!(util::bytes_into::<u32>(result)) };
+                        match FilterMode::from_u32(r) {
+                            Some(fm) => SamplerInfoResult::MipFilterMode(fm),
+                            None => SamplerInfoResult::Error(Box::new(
+                                OclError::from(format!("Error converting '{}' to \
+                                    FilterMode.", r)))),
+                        }
+                    },
+                    SamplerInfo::LodMin => {
+                        let r = unsafe { try_ir!(util::bytes_into::<f32>(result)) };
+                        SamplerInfoResult::LodMin(r)
+                    },
+                    SamplerInfo::LodMax => {
+                        let r = unsafe { try_ir!(util::bytes_into::<f32>(result)) };
+                        SamplerInfoResult::LodMax(r)
+                    },
                     // _ => SamplerInfoResult::TemporaryPlaceholderVariant(result),
                 }
             }
@@ -1623,6 +1984,9 @@ impl std::fmt::Display for SamplerInfoResult {
             SamplerInfoResult::NormalizedCoords(ref s) => write!(f, "{}", s),
             SamplerInfoResult::AddressingMode(ref s) => write!(f, "{:?}", s),
             SamplerInfoResult::FilterMode(ref s) => write!(f, "{:?}", s),
+            SamplerInfoResult::MipFilterMode(ref s) => write!(f, "{:?}", s),
+            SamplerInfoResult::LodMin(ref s) => write!(f, "{}", s),
+            SamplerInfoResult::LodMax(ref s) => write!(f, "{}", s),
             SamplerInfoResult::Error(ref err) => write!(f, "{}", err),
             // _ => panic!("SamplerInfoResult: Converting this variant to string not yet implemented."),
         }
@@ -1812,6 +2176,7 @@ pub enum ProgramBuildInfoResult {
     BuildOptions(String),
     BuildLog(String),
     BinaryType(ProgramBinaryType),
+    GlobalVariableTotalSize(usize),
     Error(Box<OclError>),
 }
 
@@ -1851,6 +2216,10 @@ impl ProgramBuildInfoResult {
                         let r = unsafe { try_ir!(util::bytes_into::<ProgramBinaryType>(result)) };
                         ProgramBuildInfoResult::BinaryType(r)
                     },
+                    ProgramBuildInfo::GlobalVariableTotalSize => {
+                        let r = unsafe { try_ir!(util::bytes_into::<usize>(result)) };
+                        ProgramBuildInfoResult::GlobalVariableTotalSize(r)
+                    },
                 }
             },
             Err(err) => ProgramBuildInfoResult::Error(Box::new(err)),
@@ -1871,6 +2240,7 @@ impl std::fmt::Display for ProgramBuildInfoResult {
             ProgramBuildInfoResult::BuildOptions(ref s) => write!(f, "{}", s),
             ProgramBuildInfoResult::BuildLog(ref s) => write!(f, "{}", s),
             ProgramBuildInfoResult::BinaryType(ref s) => write!(f, "{:?}", s),
+            ProgramBuildInfoResult::GlobalVariableTotalSize(ref s) => write!(f, "{}", s),
             ProgramBuildInfoResult::Error(ref err) => write!(f, "{}", err),
         }
     }
@@ -2389,6 +2759,7 @@ pub enum ProfilingInfoResult {
     Submit(u64),
     Start(u64),
     End(u64),
+    Complete(u64),
     Error(Box<OclError>),
 }
 
@@ -2411,6 +2782,8 @@ impl ProfilingInfoResult {
                             unsafe { try_ir!(util::bytes_into::<u64>(result)) }),
                     ProfilingInfo::End => ProfilingInfoResult::End(
                             unsafe { try_ir!(util::bytes_into::<u64>(result)) }),
+                    ProfilingInfo::Complete => ProfilingInfoResult::Complete(
+                            unsafe { try_ir!(util::bytes_into::<u64>(result)) }),
                 }
             },
             Err(err) => ProfilingInfoResult::Error(Box::new(err)),
@@ -2423,6 +2796,7 @@ impl ProfilingInfoResult {
             ProfilingInfoResult::Submit(time_ns) => Ok(time_ns),
             ProfilingInfoResult::Start(time_ns) => Ok(time_ns),
             ProfilingInfoResult::End(time_ns) => Ok(time_ns),
+            ProfilingInfoResult::Complete(time_ns) => Ok(time_ns),
             ProfilingInfoResult::Error(err) => Err(*err),
         }
     }
@@ -2441,6 +2815,7 @@ impl std::fmt::Display for ProfilingInfoResult {
             ProfilingInfoResult::Submit(ref s) => write!(f, "{}", s),
             ProfilingInfoResult::Start(ref s) => write!(f, "{}", s),
             ProfilingInfoResult::End(ref s) => write!(f, "{}", s),
+            ProfilingInfoResult::Complete(ref s) => write!(f, "{}", s),
             ProfilingInfoResult::Error(ref err) => write!(f, "{}", err),
         }
     }
@@ -7,12 +7,13 @@ use std;
 use std::mem;
 // use std::ops::{Deref, DerefMut};
 use std::marker::PhantomData;
+use std::ops::Add;
 use std::collections::HashMap;
-use num::FromPrimitive;
+use num::{FromPrimitive, Zero};
 use error::{Error as OclError, Result as OclResult};
 use ffi::{self, cl_mem, cl_buffer_region, cl_context_properties, cl_platform_id};
 use ::{Mem, MemObjectType, ImageChannelOrder, ImageChannelDataType, ContextProperty,
-    PlatformId, OclPrm};
+    PlatformId, OclPrm, AddressingMode, FilterMode, DeviceType};
 
 
 // Until everything can be implemented:
@@ -29,6 +30,14 @@ pub struct OpenclVersion {
 }
 
 impl OpenclVersion {
+    pub const CL10: OpenclVersion = OpenclVersion { ver: [1, 0] };
+    pub const CL11: OpenclVersion = OpenclVersion { ver: [1, 1] };
+    pub const CL12: OpenclVersion = OpenclVersion { ver: [1, 2] };
+    pub const CL20: OpenclVersion = OpenclVersion { ver: [2, 0] };
+    pub const CL21: OpenclVersion = OpenclVersion { ver: [2, 1] };
+    pub const CL22: OpenclVersion = OpenclVersion { ver: [2, 2] };
+    pub const CL30: OpenclVersion = OpenclVersion { ver: [3, 0] };
+
     pub fn new(major: u16, minor: u16) -> OpenclVersion {
         OpenclVersion { ver: [major, minor] }
     }
@@ -37,6 +46,37 @@ impl OpenclVersion {
         OpenclVersion { ver: [u16::max_value(), u16::max_value()] }
     }
 
+    /// Returns true if this version is greater than or equal to `required`.
+    #[inline]
+    pub fn at_least(&self, required: OpenclVersion) -> bool {
+        *self >= required
+    }
+
+    /// Returns true if this version is strictly less than `other`.
+    #[inline]
+    pub fn below(&self, other: OpenclVersion) -> bool {
+        *self < other
+    }
+
+    /// Unpacks a `cl_version` (OpenCL 3.0), laid out as major (10 bits),
+    /// minor (10 bits), and patch (12 bits). The patch version is discarded
+    /// (see `::cl_version_patch`).
+    pub fn from_cl_version(packed: u32) -> OpenclVersion {
+        OpenclVersion::new((packed >> 22) as u16, ((packed >> 12) & 0x3ff) as u16)
+    }
+
+    /// Returns the patch version contained within a packed `cl_version`.
+    pub fn cl_version_patch(packed: u32) -> u16 {
+        (packed & 0xfff) as u16
+    }
+
+    /// Packs this version into a `cl_version` with a patch version of zero.
+    ///
+    /// Major and minor versions are truncated to 10 bits.
+    pub fn to_cl_version(&self) -> u32 {
+        ((self.ver[0] as u32 & 0x3ff) << 22) | ((self.ver[1] as u32 & 0x3ff) << 12)
+    }
+
     /// Parse the string `ver` and return a dual-integer result as
     /// `OpenclVersion`.
     ///
@@ -86,6 +126,28 @@ impl OpenclVersion {
     }
 }
 
+impl std::str::FromStr for OpenclVersion {
+    type Err = OclError;
+
+    /// Parses either the `"OpenCL {major}.{minor} {vendor info}"` form
+    /// returned by `PlatformInfo::Version` and `DeviceInfo::Version` or a
+    /// bare `"{major}.{minor}"`, optionally followed by other text.
+    fn from_str(ver: &str) -> OclResult<OpenclVersion> {
+        if let Ok(cl_ver) = OpenclVersion::from_info_str(ver) {
+            return Ok(cl_ver);
+        }
+
+        let first_word = ver.split_whitespace().next().unwrap_or("");
+        let mut nums = first_word.split('.').map(|n| n.parse::<u16>());
+
+        match (nums.next(), nums.next()) {
+            (Some(Ok(major)), Some(Ok(minor))) => Ok(OpenclVersion::new(major, minor)),
+            _ => OclError::err_string(format!("OpenclVersion::from_str: Error parsing version \
+                from the string: '{}'.", ver)),
+        }
+    }
+}
+
 impl From<[u16; 2]> for OpenclVersion {
     fn from(ver: [u16; 2]) -> OpenclVersion {
         OpenclVersion { ver: ver }
@@ -99,6 +161,188 @@ impl std::fmt::Display for OpenclVersion {
 }
 
 
+/// Execution duration statistics, in nanoseconds, for a set of profiled
+/// events.
+///
+/// Each duration is measured as `CL_PROFILING_COMMAND_END -
+/// CL_PROFILING_COMMAND_START`. See `::profiling_summary`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProfilingSummary {
+    pub count: usize,
+    pub total: u64,
+    pub min: u64,
+    pub max: u64,
+    pub mean: u64,
+}
+
+impl std::fmt::Display for ProfilingSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} events: total: {}ns, min: {}ns, max: {}ns, mean: {}ns",
+            self.count, self.total, self.min, self.max, self.mean)
+    }
+}
+
+
+/// A commonly used set of device properties. See `::get_device_info_all`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceSummary {
+    pub name: String,
+    pub vendor: String,
+    pub device_type: DeviceType,
+    pub max_compute_units: u32,
+    pub max_work_group_size: usize,
+    pub max_work_item_sizes: Vec<usize>,
+    pub global_mem_size: u64,
+    pub local_mem_size: u64,
+    pub max_clock_frequency: u32,
+    pub version: OpenclVersion,
+    pub extensions: String,
+}
+
+
+/// A half-precision (16 bit) floating point value (`cl_half`).
+///
+/// Stored as its raw IEEE 754 binary16 bits. Use `from_f32` and `to_f32` to
+/// convert to and from host floats (rounding to nearest, ties to even).
+///
+/// Buffers of `ClHalf` may be created, read, and written on any device.
+/// Using `half` values within kernels (other than through `vload_half` and
+/// `vstore_half`) requires the `cl_khr_fp16` extension (see
+/// `::device_has_fp16`). `DeviceInfo::PreferredVectorWidthHalf` is zero on
+/// devices without it.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ClHalf(pub u16);
+
+impl ClHalf {
+    /// Returns a new `ClHalf` from its raw bits.
+    pub fn from_bits(bits: u16) -> ClHalf {
+        ClHalf(bits)
+    }
+
+    /// Returns the raw bits of this value.
+    pub fn to_bits(&self) -> u16 {
+        self.0
+    }
+
+    /// Converts `val` to the nearest half-precision value.
+    ///
+    /// Values too large to be represented become infinite and values too
+    /// small become (signed) zero. NaNs remain NaNs.
+    pub fn from_f32(val: f32) -> ClHalf {
+        let x = val.to_bits();
+        let sign = ((x >> 16) & 0x8000) as u16;
+        let exp = ((x >> 23) & 0xff) as i32;
+        let man = x & 0x7f_ffff;
+
+        // Infinity and NaN (keeping NaNs quiet and non-zero):
+        if exp == 0xff {
+            let nan = if man != 0 { 0x200 | (man >> 13) as u16 } else { 0 };
+            return ClHalf(sign | 0x7c00 | nan);
+        }
+
+        let half_exp = exp - 127 + 15;
+
+        if half_exp >= 0x1f {
+            return ClHalf(sign | 0x7c00);
+        }
+
+        // Subnormal (or zero) results:
+        if half_exp <= 0 {
+            if half_exp < -10 { return ClHalf(sign); }
+            let man = man | 0x80_0000;
+            let shift = (14 - half_exp) as u32;
+            let half_man = man >> shift;
+            let rem = man & ((1 << shift) - 1);
+            let halfway = 1 << (shift - 1);
+            let rounded = if rem > halfway || (rem == halfway && half_man & 1 == 1) {
+                half_man + 1
+            } else {
+                half_man
+            };
+            // A carry out of the mantissa correctly yields the smallest normal:
+            return ClHalf(sign | rounded as u16);
+        }
+
+        let mut bits = ((half_exp as u32) << 10) | (man >> 13);
+        let rem = man & 0x1fff;
+        // A carry out of the mantissa correctly increments the exponent (and
+        // overflows to infinity):
+        if rem > 0x1000 || (rem == 0x1000 && bits & 1 == 1) { bits += 1; }
+        ClHalf(sign | bits as u16)
+    }
+
+    /// Converts this value to an `f32`. The conversion is exact.
+    pub fn to_f32(&self) -> f32 {
+        let h = self.0 as u32;
+        let sign = (h & 0x8000) << 16;
+        let exp = (h >> 10) & 0x1f;
+        let man = h & 0x3ff;
+
+        let bits = if exp == 0 {
+            if man == 0 {
+                sign
+            } else {
+                // Normalize the subnormal:
+                let mut exp = 127 - 15 + 1;
+                let mut man = man;
+                while man & 0x400 == 0 { man <<= 1; exp -= 1; }
+                sign | (exp << 23) | ((man & 0x3ff) << 13)
+            }
+        } else if exp == 0x1f {
+            sign | 0x7f80_0000 | (man << 13)
+        } else {
+            sign | ((exp + 127 - 15) << 23) | (man << 13)
+        };
+
+        f32::from_bits(bits)
+    }
+}
+
+impl From<f32> for ClHalf {
+    fn from(val: f32) -> ClHalf {
+        ClHalf::from_f32(val)
+    }
+}
+
+impl From<ClHalf> for f32 {
+    fn from(val: ClHalf) -> f32 {
+        val.to_f32()
+    }
+}
+
+impl Add for ClHalf {
+    type Output = ClHalf;
+
+    fn add(self, rhs: ClHalf) -> ClHalf {
+        ClHalf::from_f32(self.to_f32() + rhs.to_f32())
+    }
+}
+
+impl Zero for ClHalf {
+    fn zero() -> ClHalf {
+        ClHalf(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 & 0x7fff == 0
+    }
+}
+
+unsafe impl OclPrm for ClHalf {}
+
+
+/// A receiver of kernel execution durations from a profiling command queue.
+///
+/// Register with `::set_profiling_sink`. `record` is called from a thread
+/// owned by the OpenCL implementation and should return quickly.
+pub trait ProfilingSink: Send + Sync {
+    /// Records that the kernel (or labeled command) `kernel_name` executed
+    /// for `duration_ns` nanoseconds.
+    fn record(&self, kernel_name: &str, duration_ns: u64);
+}
+
+
 // cl_context_properties enum  Property value  Description
 //
 // CL_CONTEXT_PLATFORM cl_platform_id  Specifies the platform to use.
@@ -350,6 +594,24 @@ impl ContextProperties {
         }
     }
 
+    /// Returns an error with the `Conversion` kind if an OpenGL (or EGL)
+    /// sharing property is specified without a platform.
+    ///
+    /// A CGL sharegroup may be specified alone, as is usual on macOS.
+    pub fn validate(&self) -> OclResult<()> {
+        let requires_platform = [ContextProperty::GlContextKhr, ContextProperty::GlxDisplayKhr,
+            ContextProperty::WglHdcKhr, ContextProperty::EglDisplayKhr];
+
+        if self.get_platform().is_none() {
+            if let Some(prop) = requires_platform.iter().find(|p| self.props.contains_key(p)) {
+                return OclError::err_conversion(format!("ContextProperties::validate: A \
+                    platform must be specified when using the '{:?}' property.", prop));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns true if this set of context properties specifies any OpenGL
     /// context or sharegroup to associate with.
     pub fn contains_gl_context_or_sharegroup(&self) -> bool {
@@ -364,11 +626,17 @@ impl ContextProperties {
     // Return type is `Vec<cl_context_properties>` => `Vec<isize>`
     //
     // [FIXME]: Change return type to `Vec<(cl_context_properties, isize)>`
+    ///
+    /// The platform (if any) is always emitted first, followed by the
+    /// remaining properties ordered by key.
     pub fn to_raw(&self) -> Vec<isize> {
         let mut props_raw = Vec::with_capacity(32);
 
+        let mut props: Vec<_> = self.props.iter().collect();
+        props.sort_by_key(|&(key, _)| (*key != ContextProperty::Platform, *key as isize));
+
         // For each property ...
-        for (key, val) in self.props.iter() {
+        for (key, val) in props.into_iter() {
             // convert both the kind of property (a u32 originally) and
             // the value (variable type/size) to an isize:
             match *val {
@@ -555,6 +823,120 @@ impl From<ContextProperties> for Vec<isize> {
 
 
 
+/// A list of sampler properties for use with
+/// `::create_sampler_with_properties`.
+///
+/// Any property left unspecified takes its OpenCL default. The mipmap
+/// properties (`mip_filter_mode`, `lod_min`, and `lod_max`) require the
+/// `cl_khr_mipmap_image` extension.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SamplerProperties {
+    normalized_coords: Option<bool>,
+    addressing_mode: Option<AddressingMode>,
+    filter_mode: Option<FilterMode>,
+    mip_filter_mode: Option<FilterMode>,
+    lod_min: Option<f32>,
+    lod_max: Option<f32>,
+}
+
+impl SamplerProperties {
+    /// Returns an empty new list of sampler properties.
+    pub fn new() -> SamplerProperties {
+        SamplerProperties::default()
+    }
+
+    /// Specifies whether image coordinates are normalized (builder-style).
+    pub fn normalized_coords(mut self, normalized_coords: bool) -> SamplerProperties {
+        self.normalized_coords = Some(normalized_coords);
+        self
+    }
+
+    /// Specifies the addressing mode (builder-style).
+    pub fn addressing_mode(mut self, addressing_mode: AddressingMode) -> SamplerProperties {
+        self.addressing_mode = Some(addressing_mode);
+        self
+    }
+
+    /// Specifies the filter mode (builder-style).
+    pub fn filter_mode(mut self, filter_mode: FilterMode) -> SamplerProperties {
+        self.filter_mode = Some(filter_mode);
+        self
+    }
+
+    /// Specifies the filter mode used between mipmap levels (builder-style).
+    pub fn mip_filter_mode(mut self, mip_filter_mode: FilterMode) -> SamplerProperties {
+        self.mip_filter_mode = Some(mip_filter_mode);
+        self
+    }
+
+    /// Specifies the minimum and maximum level of detail (builder-style).
+    pub fn lod_range(mut self, lod_min: f32, lod_max: f32) -> SamplerProperties {
+        self.lod_min = Some(lod_min);
+        self.lod_max = Some(lod_max);
+        self
+    }
+
+    /// Returns true if any property requiring `cl_khr_mipmap_image` has been
+    /// specified.
+    pub fn uses_mipmaps(&self) -> bool {
+        self.mip_filter_mode.is_some() || self.lod_min.is_some() || self.lod_max.is_some()
+    }
+
+    /// Returns the `(normalized_coords, addressing_mode, filter_mode)`
+    /// arguments equivalent to these properties for use with
+    /// `::create_sampler`, substituting OpenCL defaults for any unspecified
+    /// values.
+    ///
+    /// Returns `None` if any property unsupported by `::create_sampler` (such
+    /// as a mipmap property) has been specified.
+    pub fn to_create_sampler_args(&self) -> Option<(bool, AddressingMode, FilterMode)> {
+        if self.uses_mipmaps() { return None; }
+
+        Some((self.normalized_coords.unwrap_or(true),
+            self.addressing_mode.unwrap_or(AddressingMode::Clamp),
+            self.filter_mode.unwrap_or(FilterMode::Nearest)))
+    }
+
+    /// Returns a zero-terminated list of raw `cl_sampler_properties`.
+    ///
+    /// LOD values are stored as the bit pattern of a `cl_float` in the low
+    /// bytes of their property value.
+    pub fn to_raw(&self) -> Vec<u64> {
+        let mut props = Vec::with_capacity(13);
+
+        if let Some(nc) = self.normalized_coords {
+            props.push(ffi::CL_SAMPLER_NORMALIZED_COORDS as u64);
+            props.push(nc as u64);
+        }
+        if let Some(am) = self.addressing_mode {
+            props.push(ffi::CL_SAMPLER_ADDRESSING_MODE as u64);
+            props.push(am as u64);
+        }
+        if let Some(fm) = self.filter_mode {
+            props.push(ffi::CL_SAMPLER_FILTER_MODE as u64);
+            props.push(fm as u64);
+        }
+        if let Some(mfm) = self.mip_filter_mode {
+            props.push(::SamplerInfo::MipFilterMode as u64);
+            props.push(mfm as u64);
+        }
+        if let Some(lod_min) = self.lod_min {
+            props.push(::SamplerInfo::LodMin as u64);
+            props.push(lod_min.to_bits() as u64);
+        }
+        if let Some(lod_max) = self.lod_max {
+            props.push(::SamplerInfo::LodMax as u64);
+            props.push(lod_max.to_bits() as u64);
+        }
+
+        props.push(0);
+        props
+    }
+}
+
+
+
 /// Defines a buffer region for creating a sub-buffer.
 ///
 /// ### Info (from [SDK](https://www.khronos.org/registry/cl/sdk/1.2/docs/man/xhtml/clCreateSubBuffer.html))
@@ -659,7 +1041,7 @@ pub type ImageFormatParseResult = Result<ImageFormat, ImageFormatParseError>;
 /// image_channel_data_type values of CL_UNORM_SHORT_565, CL_UNORM_SHORT_555 and CL_UNORM_INT_101010 are special cases of packed image formats where the channels of each element are packed into a single unsigned short or unsigned int. For these special packed image formats, the channels are normally packed with the first channel in the most significant bits of the bitfield, and successive channels occupying progressively less significant locations. For CL_UNORM_SHORT_565, R is in bits 15:11, G is in bits 10:5 and B is in bits 4:0. For CL_UNORM_SHORT_555, bit 15 is undefined, R is in bits 14:10, G in bits 9:5 and B in bits 4:0. For CL_UNORM_INT_101010, bits 31:30 are undefined, R is in bits 29:20, G in bits 19:10 and B in bits 9:0.
 /// OpenCL implementations must maintain the minimum precision specified by the number of bits in image_channel_data_type. If the image format specified by image_channel_order, and image_channel_data_type cannot be supported by the OpenCL implementation, then the call to clCreateImage will return a NULL memory object.
 ///
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ImageFormat {
     pub channel_order: ImageChannelOrder,
     pub channel_data_type: ImageChannelDataType,
@@ -731,6 +1113,50 @@ impl ImageFormat {
         }
     }
 
+    /// Returns the number of channels described by the channel order.
+    ///
+    /// Padded orders (`Rx`, `Rgx`, `Rgbx`) include the padding channel.
+    pub fn channel_count(&self) -> u8 {
+        match self.channel_order {
+            ImageChannelOrder::R | ImageChannelOrder::A | ImageChannelOrder::Intensity |
+                ImageChannelOrder::Luminance | ImageChannelOrder::Depth => 1,
+            ImageChannelOrder::Rg | ImageChannelOrder::Ra | ImageChannelOrder::Rx |
+                ImageChannelOrder::DepthStencil => 2,
+            ImageChannelOrder::Rgb | ImageChannelOrder::Rgx => 3,
+            ImageChannelOrder::Rgba | ImageChannelOrder::Bgra | ImageChannelOrder::Argb |
+                ImageChannelOrder::Rgbx => 4,
+        }
+    }
+
+    /// Returns the size in bytes of a single pixel, determined by both the
+    /// channel order and the channel data type.
+    ///
+    /// Packed data types (`UnormShort565`, `UnormShort555`, `UnormInt101010`)
+    /// describe an entire pixel regardless of the channel count. `DepthStencil`
+    /// formats are packed into 4 (`UnormInt24`) or 8 (`Float`) bytes.
+    pub fn bytes_per_pixel(&self) -> usize {
+        match self.channel_data_type {
+            ImageChannelDataType::UnormShort565 | ImageChannelDataType::UnormShort555 => 2,
+            ImageChannelDataType::UnormInt101010 => 4,
+            _ if self.channel_order == ImageChannelOrder::DepthStencil => {
+                match self.channel_data_type {
+                    ImageChannelDataType::Float => 8,
+                    _ => 4,
+                }
+            },
+            ImageChannelDataType::SnormInt8 | ImageChannelDataType::UnormInt8 |
+                ImageChannelDataType::SignedInt8 | ImageChannelDataType::UnsignedInt8 => {
+                self.channel_count() as usize
+            },
+            ImageChannelDataType::SnormInt16 | ImageChannelDataType::UnormInt16 |
+                ImageChannelDataType::SignedInt16 | ImageChannelDataType::UnsignedInt16 |
+                ImageChannelDataType::HalfFloat => self.channel_count() as usize * 2,
+            ImageChannelDataType::UnormInt24 => self.channel_count() as usize * 3,
+            ImageChannelDataType::SignedInt32 | ImageChannelDataType::UnsignedInt32 |
+                ImageChannelDataType::Float => self.channel_count() as usize * 4,
+        }
+    }
+
     /// Returns the size in bytes of a pixel using the format specified by this
     /// `ImageFormat`.
     ///
@@ -873,6 +1299,68 @@ impl ImageDescriptor {
         }
     }
 
+    /// Returns a new descriptor of type `image_type` with all dimensions
+    /// set to 1 and all pitches set to 0 (to be computed by OpenCL).
+    ///
+    /// Use the builder-style methods (`::dims`, `::array_size`, `::pitches`,
+    /// and `::buffer`) to fill in the rest.
+    pub fn builder(image_type: MemObjectType) -> ImageDescriptor {
+        ImageDescriptor::new(image_type, 1, 1, 1, 1, 0, 0, None)
+    }
+
+    /// Sets the width, height, and depth in pixels.
+    pub fn dims(mut self, width: usize, height: usize, depth: usize) -> ImageDescriptor {
+        self.image_width = width;
+        self.image_height = height;
+        self.image_depth = depth;
+        self
+    }
+
+    /// Sets the number of images in an image array.
+    pub fn array_size(mut self, array_size: usize) -> ImageDescriptor {
+        self.image_array_size = array_size;
+        self
+    }
+
+    /// Sets the row and slice pitches in bytes.
+    pub fn pitches(mut self, row_pitch: usize, slc_pitch: usize) -> ImageDescriptor {
+        self.image_row_pitch = row_pitch;
+        self.image_slice_pitch = slc_pitch;
+        self
+    }
+
+    /// Sets the buffer backing a `MemObjectType::Image1dBuffer`.
+    pub fn buffer(mut self, buffer: Mem) -> ImageDescriptor {
+        self.buffer = Some(buffer);
+        self
+    }
+
+    /// Returns the number of bytes of host memory this descriptor describes
+    /// given an element (pixel) size of `pixel_bytes`, computing any zero
+    /// pitches the same way OpenCL does.
+    pub fn host_data_bytes(&self, pixel_bytes: usize) -> usize {
+        let row_pitch = if self.image_row_pitch == 0 {
+            self.image_width * pixel_bytes
+        } else {
+            self.image_row_pitch
+        };
+
+        let slc_pitch = |rows: usize| if self.image_slice_pitch == 0 {
+            row_pitch * rows
+        } else {
+            self.image_slice_pitch
+        };
+
+        match self.image_type {
+            MemObjectType::Image1d | MemObjectType::Image1dBuffer => row_pitch,
+            MemObjectType::Image2d => row_pitch * self.image_height,
+            MemObjectType::Image1dArray => slc_pitch(1) * self.image_array_size,
+            MemObjectType::Image2dArray => slc_pitch(self.image_height) * self.image_array_size,
+            MemObjectType::Image3d => slc_pitch(self.image_height) * self.image_depth,
+            MemObjectType::Buffer | MemObjectType::Pipe => 0,
+        }
+    }
+
     pub fn to_raw(&self) -> ffi::cl_image_desc {
         ffi::cl_image_desc {
             image_type: self.image_type as u32,
@@ -883,7 +1371,7 @@ impl ImageDescriptor {
             image_row_pitch: self.image_row_pitch,
             image_slice_pitch: self.image_slice_pitch,
             num_mip_levels: self.num_mip_levels,
-            num_samples: self.num_mip_levels,
+            num_samples: self.num_samples,
             buffer: match self.buffer {
                         Some(ref b) => b.as_ptr(),
                         None => 0 as cl_mem,